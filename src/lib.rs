@@ -16,11 +16,55 @@
 
 #[cfg(not(feature = "no_std"))]
 pub mod std_logger {
+    use std::collections::{HashMap, HashSet};
+    use std::fmt::Display;
+    use std::fs::OpenOptions;
+    use std::io::{self, Write};
+    use std::mem;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::thread;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[cfg(feature = "columnar")]
+    use arrow::array::{DictionaryArray, StringArray, UInt64Array};
+    #[cfg(feature = "columnar")]
+    use arrow::datatypes::{DataType, Field, Int8Type, Schema};
+    #[cfg(feature = "columnar")]
+    use arrow::record_batch::RecordBatch;
+    #[cfg(feature = "crypto")]
+    use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+    #[cfg(feature = "crypto")]
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+    #[cfg(feature = "otel")]
+    use opentelemetry::logs::{LogRecord, Severity};
+    #[cfg(feature = "rusqlite")]
+    use rusqlite::{params, Connection};
+    #[cfg(feature = "serde")]
+    use serde_json::{Map, Value};
+    #[cfg(feature = "crypto")]
+    use std::fs::{self, File};
+    #[cfg(feature = "tcp")]
+    use std::io::BufWriter;
+    #[cfg(feature = "tcp")]
+    use std::net::TcpStream;
+
+    /// The length of the nonce at the start of an encrypted log file.
+    #[cfg(feature = "crypto")]
+    const NONCE_LEN: usize = 12;
+
+    /// The command that starts the line printed by print_ci_summary, unless one is set.
+    pub const CI_SUMMARY_PREFIX: &str = "::mini_log::result";
+
     /// Used when a Marker is created with no info.
     pub const INIT_MARK: &'static str = "Logging Enabled";
     /// Used when a Marker is created with no ID.
     pub const INIT_MARK_ID: usize = 0;
 
+    /// Used as the info of the Marker added by add_separator.
+    pub const SEPARATOR_MARK: &str = "---";
+
     /// Used for testing the logging system.
     pub const TEST_LOG: &'static str = "Testing Log";
     /// Used for testing the logging ID system.
@@ -37,7 +81,7 @@ pub mod std_logger {
     pub const TEST_ERROR_ID: usize = 3;
 
     /// An enum providing types for logging
-    #[derive(Default, Clone, Debug, PartialEq)]
+    #[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
     pub enum LoggingType {
         /// Error - Used for UNRECOVERABLE Errors. Panics when it's finished parsing.
         Error,
@@ -51,8 +95,202 @@ pub mod std_logger {
         Marker,
     }
 
+    impl LoggingType {
+        /// Returns whether parse_logger prints this type to stderr.
+        fn uses_stderr(&self) -> bool {
+            matches!(self, LoggingType::Warning | LoggingType::Error)
+        }
+
+        /// Returns the byte used for this type in binary records.
+        fn type_byte(&self) -> u8 {
+            match self {
+                LoggingType::Error => 0,
+                LoggingType::Warning => 1,
+                LoggingType::Log => 2,
+                LoggingType::Marker => 3,
+            }
+        }
+
+        /// Returns the level name used when rendering this type.
+        fn level_name(&self) -> &'static str {
+            match self {
+                LoggingType::Error => "ERROR",
+                LoggingType::Warning => "WARNING",
+                LoggingType::Log => "LOG",
+                LoggingType::Marker => "MARKER",
+            }
+        }
+    }
+
+    /// A borrowed view of a single entry inside a Logger.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct LogEntry<'a> {
+        /// index - The position of the entry inside the logger.
+        pub index: usize,
+        /// log - The entry's logging info.
+        pub log: &'a str,
+        /// log_id - The entry's logging ID.
+        pub log_id: usize,
+        /// log_type - The entry's log type.
+        pub log_type: LoggingType,
+        /// log_time - When the entry was added.
+        pub log_time: SystemTime,
+        /// log_os_error - The raw OS error code and its description, if one was captured.
+        pub log_os_error: Option<(i32, &'a str)>,
+        /// log_context - The context ID that was set when the entry was added.
+        pub log_context: Option<&'a str>,
+        /// log_sequence - The entry's sequence number, increasing across every logger.
+        pub log_sequence: u64,
+        /// log_separator - Whether the entry is a separator added by add_separator.
+        pub log_separator: bool,
+        /// log_init - Whether the entry is the initial marker added by new_logger.
+        pub log_init: bool,
+    }
+
+    /// An owned entry, no longer tied to the Logger it came from.
+    /// Suitable for sending over a channel or storing in a collection.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct OwnedLogEntry {
+        /// log - The entry's logging info.
+        pub log: String,
+        /// log_id - The entry's logging ID.
+        pub log_id: usize,
+        /// log_type - The entry's log type.
+        pub log_type: LoggingType,
+        /// log_time - When the entry was added.
+        pub log_time: SystemTime,
+        /// log_os_error - The raw OS error code and its description, if one was captured.
+        pub log_os_error: Option<(i32, String)>,
+        /// log_context - The context ID that was set when the entry was added.
+        pub log_context: Option<String>,
+        /// log_sequence - The entry's sequence number, increasing across every logger.
+        pub log_sequence: u64,
+        /// log_separator - Whether the entry is a separator added by add_separator.
+        pub log_separator: bool,
+        /// log_init - Whether the entry is the initial marker added by new_logger.
+        pub log_init: bool,
+    }
+
+    impl From<LogEntry<'_>> for OwnedLogEntry {
+        fn from(entry: LogEntry<'_>) -> Self {
+            OwnedLogEntry {
+                log: entry.log.to_string(),
+                log_id: entry.log_id,
+                log_type: entry.log_type,
+                log_time: entry.log_time,
+                log_os_error: entry
+                    .log_os_error
+                    .map(|(code, desc)| (code, desc.to_string())),
+                log_context: entry.log_context.map(str::to_string),
+                log_sequence: entry.log_sequence,
+                log_separator: entry.log_separator,
+                log_init: entry.log_init,
+            }
+        }
+    }
+
+    impl OwnedLogEntry {
+        /// Borrows the entry as a LogEntry at the given index.
+        fn as_entry(&self, index: usize) -> LogEntry<'_> {
+            LogEntry {
+                index,
+                log: &self.log,
+                log_id: self.log_id,
+                log_type: self.log_type.clone(),
+                log_time: self.log_time,
+                log_os_error: self
+                    .log_os_error
+                    .as_ref()
+                    .map(|(code, desc)| (*code, desc.as_str())),
+                log_context: self.log_context.as_deref(),
+                log_sequence: self.log_sequence,
+                log_separator: self.log_separator,
+                log_init: self.log_init,
+            }
+        }
+    }
+
+    /// A tree of entries built by Logger::to_tree.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct LogTree {
+        /// roots - The entries with no parent, in logged order.
+        pub roots: Vec<LogTreeNode>,
+    }
+
+    /// A single entry inside a LogTree, along with the entries nested under it.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct LogTreeNode {
+        /// entry - The entry itself.
+        pub entry: OwnedLogEntry,
+        /// children - The entries nested under this one, in logged order.
+        pub children: Vec<LogTreeNode>,
+        /// line - The entry rendered by the logger the tree was built from.
+        line: String,
+    }
+
+    impl LogTree {
+        /// Renders every entry as a single line, indented by two spaces per level of nesting.
+        pub fn render(&self) -> String {
+            let mut out = String::new();
+            let mut stack: Vec<(usize, &LogTreeNode)> =
+                self.roots.iter().rev().map(|node| (0, node)).collect();
+            while let Some((depth, node)) = stack.pop() {
+                out.push_str(&"  ".repeat(depth));
+                out.push_str(&node.line);
+                out.push('\n');
+                stack.extend(node.children.iter().rev().map(|child| (depth + 1, child)));
+            }
+            out
+        }
+    }
+
+    /// A flag that can be set through a shared reference, while keeping Logger Sync.
+    #[derive(Default, Debug)]
+    struct Flag(AtomicBool);
+
+    impl Flag {
+        fn set(&self) {
+            self.0.store(true, Ordering::Relaxed);
+        }
+
+        fn get(&self) -> bool {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    impl Clone for Flag {
+        fn clone(&self) -> Self {
+            Flag(AtomicBool::new(self.get()))
+        }
+    }
+
+    /// Counters bumped on every add of their type, shared with whoever registered them.
+    #[derive(Default, Clone, Debug)]
+    struct Counters(Vec<(LoggingType, Arc<AtomicUsize>)>);
+
+    impl Counters {
+        fn increment(&self, log_type: &LoggingType) {
+            for (counted, counter) in &self.0 {
+                if counted == log_type {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    impl PartialEq for Counters {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.len() == other.0.len()
+                && self
+                    .0
+                    .iter()
+                    .zip(&other.0)
+                    .all(|((a_type, a), (b_type, b))| a_type == b_type && Arc::ptr_eq(a, b))
+        }
+    }
+
     /// A struct containing logging info.
-    #[derive(Default, Clone, Debug, PartialEq)]
+    #[derive(Default, Clone, Debug)]
     pub struct Logger {
         /// log - A vector of strings for containing logging info.
         log: Vec<String>,
@@ -60,16 +298,365 @@ pub mod std_logger {
         log_id: Vec<usize>,
         /// log_type - A vector of LoggingType's for containing log types.
         log_type: Vec<LoggingType>,
+        /// log_time - A vector of SystemTime's for containing when each log was added.
+        log_time: Vec<SystemTime>,
+        /// log_os_error - A vector of captured OS error codes and descriptions.
+        log_os_error: Vec<Option<(i32, String)>>,
+        /// log_context - A vector of the context IDs set when each log was added.
+        log_context: Vec<Option<String>>,
+        /// log_sequence - A vector of the sequence numbers assigned when each log was added.
+        log_sequence: Vec<u64>,
+        /// log_separator - A vector of whether each log is a separator added by add_separator.
+        log_separator: Vec<bool>,
+        /// log_init - A vector of whether each log is the initial marker added by new_logger.
+        log_init: Vec<bool>,
+        /// context - The context ID captured by entries added from now on.
+        context: Option<String>,
+        /// template - An optional template used to render each entry's prefix.
+        template: Option<String>,
+        /// spill_path - The file entries are spilled to once spill_max is exceeded.
+        spill_path: Option<PathBuf>,
+        /// spill_max - The maximum amount of entries kept in memory while spilling.
+        spill_max: usize,
+        /// spill_error - The kind and message of the last spill's error, if it failed.
+        spill_error: Option<(io::ErrorKind, String)>,
+        /// sink_path - The file flush appends rendered entries to.
+        sink_path: Option<PathBuf>,
+        /// flush_cursor - The index of the first entry not yet flushed.
+        flush_cursor: usize,
+        /// json_cursor - The index of the first entry not yet serialized by to_json_incremental.
+        json_cursor: usize,
+        /// clear_on_flush - Whether flushed entries are removed from memory.
+        clear_on_flush: bool,
+        /// autoflush_every - The amount of adds between automatic flushes, 0 disables it.
+        autoflush_every: usize,
+        /// adds_since_flush - The amount of adds since the last flush.
+        adds_since_flush: usize,
+        /// autoflush_error - The kind and message of the last autoflush's error, if it failed.
+        autoflush_error: Option<(io::ErrorKind, String)>,
+        /// parsed - Whether parse_logger or a flush has output the entries.
+        parsed: Flag,
+        /// warn_unparsed_errors - Whether dropping with unparsed Errors warns on stderr.
+        warn_unparsed_errors: bool,
+        /// ordered_output - Whether parse_logger flushes stdout and stderr in logged order.
+        ordered_output: bool,
+        /// id_names - Human readable names printed alongside IDs.
+        id_names: HashMap<usize, String>,
+        /// show_sequence - Whether each rendered entry is prefixed by its sequence number.
+        show_sequence: bool,
+        /// ci_summary_prefix - The command that starts the line printed by print_ci_summary.
+        ci_summary_prefix: Option<String>,
+        /// severity_overrides - The types forced onto entries added with a given ID.
+        severity_overrides: HashMap<usize, LoggingType>,
+        /// counters - External counters bumped on every add of their type.
+        counters: Counters,
+        /// escape_control - Whether rendered control characters are replaced by \xNN escapes.
+        escape_control: bool,
     }
 
     impl Logger {
         /// Creates a new logger
         pub fn new_logger() -> Self {
-            Self {
-                log: vec![INIT_MARK.to_string()],
-                log_id: vec![INIT_MARK_ID],
-                log_type: vec![LoggingType::Marker],
+            let mut logger = Self::default();
+            logger.warn_unparsed_errors = true;
+            logger.push_stored(LogEntry {
+                index: 0,
+                log: INIT_MARK,
+                log_id: INIT_MARK_ID,
+                log_type: LoggingType::Marker,
+                log_time: SystemTime::now(),
+                log_os_error: None,
+                log_context: None,
+                log_sequence: next_sequence(),
+                log_separator: false,
+                log_init: true,
+            });
+            logger
+        }
+
+        /// Pushes an entry onto every parallel vector, keeping them aligned.
+        /// The entry's index is ignored.
+        fn push_stored(&mut self, entry: LogEntry<'_>) {
+            self.log.push(entry.log.to_string());
+            self.log_id.push(entry.log_id);
+            self.log_type.push(entry.log_type);
+            self.log_time.push(entry.log_time);
+            self.log_os_error.push(
+                entry
+                    .log_os_error
+                    .map(|(code, desc)| (code, desc.to_string())),
+            );
+            self.log_context.push(entry.log_context.map(str::to_string));
+            self.log_sequence.push(entry.log_sequence);
+            self.log_separator.push(entry.log_separator);
+            self.log_init.push(entry.log_init);
+        }
+
+        /// Pushes a new entry with the current time.
+        fn push_entry(&mut self, log: &str, log_id: usize, log_type: LoggingType) {
+            self.push_added(log, log_id, log_type, None, false);
+        }
+
+        /// Pushes an entry added through the add methods, stamped with the current time,
+        /// context and a new sequence number, and bumps the registered counters.
+        fn push_added(
+            &mut self,
+            log: &str,
+            log_id: usize,
+            log_type: LoggingType,
+            log_os_error: Option<(i32, &str)>,
+            log_separator: bool,
+        ) {
+            self.counters.increment(&log_type);
+            let context = self.context.clone();
+            self.push_new(LogEntry {
+                index: self.log.len(),
+                log,
+                log_id,
+                log_type,
+                log_time: SystemTime::now(),
+                log_os_error,
+                log_context: context.as_deref(),
+                log_sequence: next_sequence(),
+                log_separator,
+                log_init: false,
+            });
+        }
+
+        /// Pushes a new entry, then spills and autoflushes if configured.
+        fn push_new(&mut self, entry: LogEntry<'_>) {
+            self.push_stored(entry);
+
+            // A failed spill keeps the entries in memory and is recorded for spill_error,
+            // the next add or flush_spill retries it.
+            let _ = self.spill_overflow();
+
+            self.adds_since_flush += 1;
+            if self.autoflush_every != 0 && self.adds_since_flush >= self.autoflush_every {
+                // A failed flush keeps the counter running, so the next add retries it.
+                let result = self.flush();
+                self.autoflush_error = result.as_ref().err().map(error_parts);
+            }
+        }
+
+        /// Removes the first count entries from every parallel vector.
+        fn drain_front(&mut self, count: usize) {
+            self.log.drain(..count);
+            self.log_id.drain(..count);
+            self.log_type.drain(..count);
+            self.log_time.drain(..count);
+            self.log_os_error.drain(..count);
+            self.log_context.drain(..count);
+            self.log_sequence.drain(..count);
+            self.log_separator.drain(..count);
+            self.log_init.drain(..count);
+            self.flush_cursor = self.flush_cursor.saturating_sub(count);
+            self.json_cursor = self.json_cursor.saturating_sub(count);
+        }
+
+        /// Keeps only the entries whose flag in keep is set, in a single pass over every
+        /// parallel vector. keep holds one flag per entry.
+        fn retain_entries(&mut self, keep: &[bool]) {
+            retain_by(&mut self.log, keep);
+            retain_by(&mut self.log_id, keep);
+            retain_by(&mut self.log_type, keep);
+            retain_by(&mut self.log_time, keep);
+            retain_by(&mut self.log_os_error, keep);
+            retain_by(&mut self.log_context, keep);
+            retain_by(&mut self.log_sequence, keep);
+            retain_by(&mut self.log_separator, keep);
+            retain_by(&mut self.log_init, keep);
+
+            let kept_before = |cursor: usize| keep[..cursor].iter().filter(|&&kept| kept).count();
+            self.flush_cursor = kept_before(self.flush_cursor.min(keep.len()));
+            self.json_cursor = kept_before(self.json_cursor.min(keep.len()));
+        }
+
+        /// Shortens every parallel vector to len entries.
+        fn truncate_entries(&mut self, len: usize) {
+            self.log.truncate(len);
+            self.log_id.truncate(len);
+            self.log_type.truncate(len);
+            self.log_time.truncate(len);
+            self.log_os_error.truncate(len);
+            self.log_context.truncate(len);
+            self.log_sequence.truncate(len);
+            self.log_separator.truncate(len);
+            self.log_init.truncate(len);
+            self.flush_cursor = self.flush_cursor.min(len);
+            self.json_cursor = self.json_cursor.min(len);
+        }
+
+        /// Returns the total size in bytes of every entry's info.
+        pub fn byte_size(&self) -> usize {
+            self.log.iter().map(String::len).sum()
+        }
+
+        /// Removes the oldest entries until byte_size is no more than max_bytes.
+        /// Markers are never removed, so a budget smaller than the markers alone is not reached.
+        pub fn enforce_byte_budget(&mut self, max_bytes: usize) {
+            let mut size = self.byte_size();
+            if size <= max_bytes {
+                return;
+            }
+
+            let mut keep = vec![true; self.log.len()];
+            for (i, kept) in keep.iter_mut().enumerate() {
+                if size <= max_bytes {
+                    break;
+                }
+                if self.log_type[i] != LoggingType::Marker {
+                    size -= self.log[i].len();
+                    *kept = false;
+                }
+            }
+            self.retain_entries(&keep);
+        }
+
+        /// Returns the length of every parallel vector.
+        fn vector_lens(&self) -> [usize; 9] {
+            [
+                self.log.len(),
+                self.log_id.len(),
+                self.log_type.len(),
+                self.log_time.len(),
+                self.log_os_error.len(),
+                self.log_context.len(),
+                self.log_sequence.len(),
+                self.log_separator.len(),
+                self.log_init.len(),
+            ]
+        }
+
+        /// Returns whether every parallel vector holds the same amount of entries.
+        /// Every method relies on this, and panics on an index mismatch otherwise.
+        pub fn invariant_holds(&self) -> bool {
+            let lens = self.vector_lens();
+            lens.iter().all(|&len| len == lens[0])
+        }
+
+        /// Truncates every parallel vector to the length of the shortest one,
+        /// restoring the invariant checked by invariant_holds.
+        /// Returns how many entries the longest vector lost, 0 if the invariant already held.
+        pub fn repair(&mut self) -> usize {
+            let lens = self.vector_lens();
+            let shortest = lens.iter().copied().min().unwrap_or(0);
+            let longest = lens.iter().copied().max().unwrap_or(0);
+            self.truncate_entries(shortest);
+            longest - shortest
+        }
+
+        /// Releases the unused capacity of every parallel vector back to the allocator.
+        /// Useful for long-lived loggers that grew large, then were cleared or spilled.
+        pub fn shrink_to_fit(&mut self) {
+            self.log.shrink_to_fit();
+            self.log_id.shrink_to_fit();
+            self.log_type.shrink_to_fit();
+            self.log_time.shrink_to_fit();
+            self.log_os_error.shrink_to_fit();
+            self.log_context.shrink_to_fit();
+            self.log_sequence.shrink_to_fit();
+            self.log_separator.shrink_to_fit();
+            self.log_init.shrink_to_fit();
+        }
+
+        /// Replaces every entry with entries, keeping the parallel vectors aligned.
+        fn replace_entries(&mut self, entries: Vec<OwnedLogEntry>) {
+            let (flush_cursor, json_cursor) = (self.flush_cursor, self.json_cursor);
+            self.truncate_entries(0);
+            for (i, entry) in entries.iter().enumerate() {
+                self.push_stored(entry.as_entry(i));
+            }
+            self.flush_cursor = flush_cursor.min(self.log.len());
+            self.json_cursor = json_cursor.min(self.log.len());
+        }
+
+        /// Appends the first count entries to the spill file, then removes them from memory.
+        /// Records the outcome for spill_error.
+        fn spill_front(&mut self, count: usize) -> io::Result<()> {
+            let result = self.try_spill_front(count);
+            self.spill_error = result.as_ref().err().map(error_parts);
+            result
+        }
+
+        /// Appends the first count entries to the spill file, then removes them from memory.
+        fn try_spill_front(&mut self, count: usize) -> io::Result<()> {
+            let path = match &self.spill_path {
+                Some(path) => path,
+                None => return Ok(()),
+            };
+
+            let mut out = String::new();
+            for i in 0..count {
+                out.push_str(&self.render_line(i));
+                out.push('\n');
+            }
+
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            file.write_all(out.as_bytes())?;
+            self.drain_front(count);
+            Ok(())
+        }
+
+        /// Spills the oldest entries once there are more than spill_max in memory.
+        fn spill_overflow(&mut self) -> io::Result<()> {
+            if self.spill_path.is_some() && self.log.len() > self.spill_max {
+                self.spill_front(self.log.len() - self.spill_max)?;
+            }
+            Ok(())
+        }
+
+        /// Enables spilling to disk.
+        /// Once more than max_entries are held in memory, the oldest are appended to path
+        /// and removed from the logger, keeping memory bounded for long runs.
+        /// The spill file holds the entries rendered exactly as render would,
+        /// in the order the entries were added. The file is only ever appended to.
+        /// Newlines are not escaped, so an entry whose info contains some,
+        /// e.g. one merged by join_continuations, spans several lines.
+        /// Spills happen while adding, so their errors are not returned, check spill_error instead.
+        pub fn set_spill<P: AsRef<Path>>(&mut self, path: P, max_entries: usize) {
+            self.spill_path = Some(path.as_ref().to_path_buf());
+            self.spill_max = max_entries;
+            self.spill_error = None;
+        }
+
+        /// Returns the error of the last spill, if it failed, e.g. because the spill file
+        /// cannot be created. While spills fail, entries pile up in memory past max_entries.
+        /// Cleared once a spill succeeds.
+        pub fn spill_error(&self) -> Option<io::Error> {
+            self.spill_error
+                .as_ref()
+                .map(|(kind, message)| io::Error::new(*kind, message.as_str()))
+        }
+
+        /// Finalizes the spill file by appending every entry still held in memory.
+        /// Afterwards, the spill file contains the complete log and the logger is empty.
+        /// Does nothing if spilling is not enabled.
+        pub fn flush_spill(&mut self) -> io::Result<()> {
+            self.spill_front(self.log.len())?;
+            if self.spill_path.is_some() {
+                self.parsed.set();
             }
+            Ok(())
+        }
+
+        /// Forces every entry added with log_id to be stored as kind,
+        /// whichever add method is used, e.g. to make ID 500 always an Error.
+        /// Overrides take precedence over the add method, so add_log(msg, 500) stores an Error.
+        /// IDs without an override keep the type of the add method used.
+        /// Applies to add_marker, add_log, add_warning, add_error, add_os_error and log_results.
+        /// Separators and scope markers stay Markers, and entries already added are left unchanged.
+        pub fn set_severity_override(&mut self, id: usize, kind: LoggingType) {
+            self.severity_overrides.insert(id, kind);
+        }
+
+        /// Returns the type an entry added with log_id is stored as,
+        /// which is its override if one was set, and log_type otherwise.
+        fn severity_for(&self, log_id: usize, log_type: LoggingType) -> LoggingType {
+            self.severity_overrides
+                .get(&log_id)
+                .cloned()
+                .unwrap_or(log_type)
         }
 
         /// Adds a new Marker to your logger
@@ -77,320 +664,3474 @@ pub mod std_logger {
         pub fn add_marker(&mut self, log: Option<&str>, log_id: Option<usize>) {
             let log_str = log.unwrap_or(INIT_MARK);
             let log_id_val = log_id.unwrap_or(INIT_MARK_ID);
-            self.log.push(log_str.to_string());
-            self.log_id.push(log_id_val);
-            self.log_type.push(LoggingType::Marker);
+            self.push_entry(
+                log_str,
+                log_id_val,
+                self.severity_for(log_id_val, LoggingType::Marker),
+            );
+        }
+
+        /// Adds a separator to your logger, for visually grouping phases.
+        /// A separator is a Marker whose info is SEPARATOR_MARK and whose ID is INIT_MARK_ID,
+        /// flagged by log_separator, so a Marker that merely has the same info is not one.
+        /// It is rendered as a bare --- line, and is skipped by queries over the logged entries,
+        /// e.g. distinct_id_count, ended_cleanly, assert_sequence and to_sparkline,
+        /// and reclassify_by_rules never changes its type.
+        pub fn add_separator(&mut self) {
+            self.push_added(
+                SEPARATOR_MARK,
+                INIT_MARK_ID,
+                LoggingType::Marker,
+                None,
+                true,
+            );
         }
 
         /// Adds a new Log to your logger
         pub fn add_log(&mut self, log: &str, log_id: usize) {
-            self.log.push(log.to_string());
-            self.log_id.push(log_id);
-            self.log_type.push(LoggingType::Log);
+            self.push_entry(log, log_id, self.severity_for(log_id, LoggingType::Log));
         }
 
         /// Adds a new Warning to your logger
         pub fn add_warning(&mut self, log: &str, log_id: usize) {
-            self.log.push(log.to_string());
-            self.log_id.push(log_id);
-            self.log_type.push(LoggingType::Warning);
+            self.push_entry(log, log_id, self.severity_for(log_id, LoggingType::Warning));
         }
 
         /// Adds a new Error to your logger
         pub fn add_error(&mut self, log: &str, log_id: usize) {
-            self.log.push(log.to_string());
-            self.log_id.push(log_id);
-            self.log_type.push(LoggingType::Error);
+            self.push_entry(log, log_id, self.severity_for(log_id, LoggingType::Error));
         }
 
-        /// Parses the Logger
-        /// Behavior with the following:
-        /// A Marker - Prints out the Marker Info.
-        /// A Log - Prints out the Log Info and Log ID.
-        /// A Warning - Error Prints the Warning Info and Warning ID.
-        /// An Error - Error Prints the Error Info and Error ID, then panics.
-        pub fn parse_logger(&self) {
-            let mut last_error: Option<usize> = None;
+        /// Sets whether dropping the logger warns about Errors that were never output.
+        /// Enabled by default for loggers created by new_logger, and inherited by child.
+        /// Loggers built from other loggers' entries, e.g. by chunks, aggregate or from_aos,
+        /// never warn, since the entries they copy are still held by their source.
+        pub fn set_warn_unparsed_errors(&mut self, warn: bool) {
+            self.warn_unparsed_errors = warn;
+        }
 
-            for i in 0..self.log.len() {
-                match self.log_type[i] {
-                    LoggingType::Marker => {
-                        println!("[MARKER]: {}", self.log[i])
-                    }
-                    LoggingType::Log => {
-                        println!("[LOG]: Info: {}; Info ID: {}", self.log[i], self.log_id[i])
-                    }
-                    LoggingType::Warning => {
-                        eprintln!(
-                            "[WARNING]: Warning: {}; Warning ID: {}",
-                            self.log[i], self.log_id[i]
-                        )
-                    }
-                    LoggingType::Error => {
-                        eprintln!(
-                            "[ERROR]: Error: {}; Error ID: {}",
-                            self.log[i], self.log_id[i]
-                        );
-                        last_error = Some(i);
-                    }
-                }
-            }
+        /// Sets whether parse_logger keeps stdout and stderr lines in their logged order.
+        /// When on, consecutive lines for the same stream are buffered together,
+        /// and each buffer is flushed before switching streams,
+        /// so redirecting both streams into one file keeps the entries in order.
+        pub fn set_ordered_output(&mut self, ordered: bool) {
+            self.ordered_output = ordered;
+        }
 
-            if let Some(idx) = last_error {
-                panic!(
-                    "[ERROR]: Final Error: Error: {}; Error ID: {}",
-                    self.log[idx], self.log_id[idx]
-                )
-            }
+        /// Registers counter to be incremented every time an entry of log_type is added,
+        /// so other threads can read counts without locking the logger.
+        /// Only entries added through the add methods are counted, absorbed or rebuilt ones are not.
+        /// Removing entries never decrements the counter. Clones of the logger share its counters.
+        pub fn register_counter(&mut self, log_type: LoggingType, counter: Arc<AtomicUsize>) {
+            self.counters.0.push((log_type, counter));
         }
-    }
-}
 
-/// The no_std implementation of std_logger
-// #[cfg(feature = "no_std")]
-pub mod no_std_logger {
-    use core::{ffi::c_char, option::Option, result::Result, usize};
+        /// Sets the command that starts the line printed by print_ci_summary.
+        /// Defaults to CI_SUMMARY_PREFIX.
+        pub fn set_ci_summary_prefix(&mut self, prefix: &str) {
+            self.ci_summary_prefix = Some(prefix.to_string());
+        }
 
-    /// Enum for Overflow Errors
-    /// Implements Debug for debugging
-    #[derive(Debug)]
-    pub enum OverflowError {
-        /// Enum Type for when a Log is over it's expected length.
-        BufferEntryOverflow,
-        /// Enum Type for when accessing beyond the length of an array.
-        InvalidIndex(usize),
-    }
+        /// Returns the line printed by print_ci_summary,
+        /// e.g. ::mini_log::result errors=2 warnings=5.
+        pub fn ci_summary(&self) -> String {
+            let count =
+                |log_type: LoggingType| self.log_type.iter().filter(|t| **t == log_type).count();
+            format!(
+                "{} errors={} warnings={}",
+                self.ci_summary_prefix
+                    .as_deref()
+                    .unwrap_or(CI_SUMMARY_PREFIX),
+                count(LoggingType::Error),
+                count(LoggingType::Warning)
+            )
+        }
 
-    /// The maximum amount of Log's that can be made.
-    pub const MAX_LOG_ENTRIES: usize = 1024;
-    /// The maximum length for a Log
-    pub const MAX_LOG_LENGTH: usize = 1024;
+        /// Prints ci_summary as a single line to stderr,
+        /// in a form CI systems can parse, like GitHub Actions workflow commands.
+        pub fn print_ci_summary(&self) {
+            eprintln!("{}", self.ci_summary());
+        }
 
-    /// The no_std implementation of LoggingType
-    /// This does not implement any confort types.
-    /// However, it does implement Copy and Clone for easy initalization.
-    #[derive(Copy, Clone)]
-    pub enum LoggingTypeNS {
-        /// Error - For UNRECOVERABLE Error
-        /// WARNING! Once the parse_logger_ns function finishes parsing,
-        /// it will crash the program if it includes this type.
-        /// This is intentional behavior.
-        Error,
-        /// Warning - Used for potentially hazardous behavior logging.
-        Warning,
-        /// Log - Used for basic information logging.
-        Log,
-        /// Marker - Used for marking when a certain point in a program happens.
-        Marker,
-    }
+        /// Sets the file that flush appends rendered entries to.
+        pub fn set_sink<P: AsRef<Path>>(&mut self, path: P) {
+            self.sink_path = Some(path.as_ref().to_path_buf());
+        }
 
-    pub struct LoggerNS {
-        /// log - An array of an array of 1024 c_char's of 1024 arrays
-        /// To better describe it, 1024 arrays can be put into this array.
-        /// Inside those arrays, 1024 c_char's can be put into it.
-        log: [[c_char; MAX_LOG_LENGTH]; MAX_LOG_ENTRIES],
-        /// log_id - An array of 1024 usize's
-        log_id: [usize; MAX_LOG_ENTRIES],
-        /// log_type - An array of 1024 LoggingTypeNS's
-        log_type: [LoggingTypeNS; MAX_LOG_ENTRIES],
-        /// free_slots - an array of 1024 booleans for constant time lookup
-        // TODO: Replace this with a u16 counter for 0(1) lookup time.
-        free_slots: [bool; MAX_LOG_ENTRIES]
-    }
+        /// Sets whether flushed entries are removed from the logger.
+        pub fn set_clear_on_flush(&mut self, clear: bool) {
+            self.clear_on_flush = clear;
+        }
 
-    impl LoggerNS {
-        /// Creates a new LoggerNS with initalized data
-        /// Automatically sets all values to the following:
-        ///  - log - A null-terminated array
-        ///  - log_id - An array of 0
-        ///  - log_type - An array of LoggingTypeNS::Marker
-        ///  - free_slots - An array of 1024 true's (Tells search that the entire array is empty)
-        pub fn new_logger_ns() -> Self {
-            Self {
-                log: [[0; 1024]; 1024],
-                log_id: [0; 1024],
-                log_type: [LoggingTypeNS::Marker; 1024],
-                // To replace with u16 counter, set the counter value to 0
-                free_slots: [true; 1024],
-            }
+        /// Flushes the logger automatically after every n adds.
+        /// The counter resets after each flush, and an n of 0 disables autoflushing.
+        /// Autoflushes happen while adding, so their errors are not returned,
+        /// check autoflush_error instead.
+        pub fn set_autoflush_every(&mut self, n: usize) {
+            self.autoflush_every = n;
+            self.adds_since_flush = 0;
+            self.autoflush_error = None;
         }
 
-        /// Goes through the arrays, and gets the first empty slot.
-        // Potential: Remove this Function (if the way slot accessing changes allows this)
-        fn get_next_avaliable_slot(&mut self) -> Option<usize> {
-            for i in 0..MAX_LOG_ENTRIES {
-                if self.free_slots[i] {
-                    // If the function is not removed, this would turn into: ` self.free_slot += 1 '
-                    self.free_slots[i] = false;
-                    return Some(i);
-                }
-            }
-            None
+        /// Returns the error of the last autoflush, if it failed, e.g. because the sink
+        /// cannot be written. A failed autoflush is retried on every following add.
+        /// Cleared once an autoflush succeeds.
+        pub fn autoflush_error(&self) -> Option<io::Error> {
+            self.autoflush_error
+                .as_ref()
+                .map(|(kind, message)| io::Error::new(*kind, message.as_str()))
         }
 
-        /// Adds a marker. 
-        /// Inputs : Option<[c_char; 1024], Option<usize>>
-        pub fn add_marker(
-            &mut self,
-            mut message: Option<[c_char; MAX_LOG_ENTRIES]>,
-            mut id: Option<usize>,
-        ) -> Result<usize, OverflowError> {
-            if message == None {
-                let message_default = "Marker Place";
-                let mut arr: [c_char; MAX_LOG_ENTRIES] = [0; 1024];
-                for i in 0..message_default.len() {
-                    arr[i] = message_default.as_bytes()[i] as c_char;
+        /// Appends every entry added since the last flush to the sink, rendered as render would.
+        /// If clear_on_flush is set, the flushed entries are then removed from the logger.
+        /// Without a sink, nothing is written.
+        pub fn flush(&mut self) -> io::Result<()> {
+            if let Some(path) = &self.sink_path {
+                let mut out = String::new();
+                for i in self.flush_cursor..self.log.len() {
+                    out.push_str(&self.render_line(i));
+                    out.push('\n');
                 }
 
-                message = Some(arr);
-            }
-            
+                let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+                file.write_all(out.as_bytes())?;
+                file.flush()?;
+                self.parsed.set();
 
-            if id == None {
-                id = Some(0);
+                if self.clear_on_flush {
+                    let len = self.log.len();
+                    self.drain_front(len);
+                }
+                self.flush_cursor = self.log.len();
             }
-            if let Some(slot) = self.get_next_avaliable_slot() {
-                self.log[slot] = Option::expect(message, "ID is None, failed to change value");
-                self.log_id[slot] = Option::expect(id, "ID is None, failed to change value");
-                self.log_type[slot] = LoggingTypeNS::Marker;
-                Ok(slot)
+
+            self.adds_since_flush = 0;
+            Ok(())
+        }
+
+        /// Writes every entry to out, rendered as render would, in batches of lines_per_flush lines.
+        /// out is flushed after each batch, then pause is slept before the next one,
+        /// so a slow terminal is never handed the whole log at once.
+        /// A lines_per_flush of 0 writes everything in a single batch.
+        /// Stops at the first I/O error and returns it, leaving the remaining lines unwritten.
+        pub fn flush_throttled<W: Write>(
+            &self,
+            out: &mut W,
+            lines_per_flush: usize,
+            pause: Duration,
+        ) -> io::Result<()> {
+            let batch_len = if lines_per_flush == 0 {
+                self.log.len().max(1)
             } else {
-                Err(OverflowError::BufferEntryOverflow)
+                lines_per_flush
+            };
+
+            for start in (0..self.log.len()).step_by(batch_len) {
+                if start != 0 {
+                    thread::sleep(pause);
+                }
+                let mut batch = String::new();
+                for i in start..self.log.len().min(start + batch_len) {
+                    batch.push_str(&self.render_line(i));
+                    batch.push('\n');
+                }
+                out.write_all(batch.as_bytes())?;
+                out.flush()?;
             }
+
+            self.parsed.set();
+            Ok(())
         }
 
-        /// Adds a Log to your Logger.
-        /// Inputs: [c_char; 1024], usize
-        pub fn add_log(
+        /// Aggregates the entries of several loggers into one.
+        /// Entries are merged, sorted by when they were added, and exact duplicates
+        /// (same info, ID, type and time) are removed, keeping the first.
+        /// Entries added at the same time keep the order of the loggers they came from.
+        /// The returned logger has no configuration and no extra initial marker.
+        pub fn aggregate(loggers: &[Logger]) -> Logger {
+            let mut merged: Vec<LogEntry<'_>> = loggers.iter().flat_map(Logger::entries).collect();
+            merged.sort_by_key(|entry| entry.log_time);
+
+            let mut aggregated = Logger::default();
+            for (i, entry) in merged.iter().enumerate() {
+                let is_duplicate = merged[..i]
+                    .iter()
+                    .rev()
+                    .take_while(|kept| kept.log_time == entry.log_time)
+                    .any(|kept| {
+                        kept.log == entry.log
+                            && kept.log_id == entry.log_id
+                            && kept.log_type == entry.log_type
+                    });
+                if !is_duplicate {
+                    aggregated.push_stored(entry.clone());
+                }
+            }
+            aggregated
+        }
+
+        /// Splits the logger into sub-loggers of up to size consecutive entries each.
+        /// Chunks keep the order of the entries, and only the first chunk holds the
+        /// initial marker, since chunks do not get one of their own.
+        /// Chunks carry no configuration.
+        /// Panics if size is 0, like slice chunking.
+        pub fn chunks(&self, size: usize) -> Vec<Logger> {
+            assert!(size != 0, "chunk size must be non-zero");
+
+            let mut chunks = Vec::new();
+            for start in (0..self.log.len()).step_by(size) {
+                let mut chunk = Logger::default();
+                for i in start..(start + size).min(self.log.len()) {
+                    chunk.push_stored(self.entry(i));
+                }
+                chunks.push(chunk);
+            }
+            chunks
+        }
+
+        /// Writes the rendered logger to path, encrypted with ChaCha20-Poly1305 under key.
+        /// The file starts with the random 12 byte nonce, followed by the ciphertext and its tag.
+        /// Read it back with read_from_file_encrypted.
+        #[cfg(feature = "crypto")]
+        pub fn write_to_file_encrypted<P: AsRef<Path>>(
+            &self,
+            path: P,
+            key: &[u8; 32],
+        ) -> io::Result<()> {
+            let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+            let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+            let ciphertext = cipher
+                .encrypt(&nonce, self.render().as_bytes())
+                .map_err(|_| io::Error::other("failed to encrypt the log"))?;
+
+            let mut file = File::create(path)?;
+            file.write_all(&nonce)?;
+            file.write_all(&ciphertext)
+        }
+
+        /// Reads and decrypts a file written by write_to_file_encrypted, returning the rendered log.
+        /// Fails with InvalidData if the file is malformed, the key is wrong,
+        /// or the contents were tampered with.
+        #[cfg(feature = "crypto")]
+        pub fn read_from_file_encrypted<P: AsRef<Path>>(
+            path: P,
+            key: &[u8; 32],
+        ) -> io::Result<String> {
+            let data = fs::read(path)?;
+            if data.len() < NONCE_LEN {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "encrypted log is missing its nonce",
+                ));
+            }
+
+            let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+            let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+            let plaintext = cipher
+                .decrypt(Nonce::from_slice(nonce), ciphertext)
+                .map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidData, "failed to decrypt the log")
+                })?;
+            String::from_utf8(plaintext)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+        }
+
+        /// Connects to addr and sends every entry over TCP as newline delimited JSON.
+        /// Each line holds the entry's type, id, info and time, e.g.
+        /// {"type":"LOG","id":1,"message":"Testing Log","time":1700000000.000}
+        /// Fails if the connection cannot be made or a write fails.
+        #[cfg(feature = "tcp")]
+        pub fn stream_to_tcp(&self, addr: &str) -> io::Result<()> {
+            let mut stream = BufWriter::new(TcpStream::connect(addr)?);
+            for i in 0..self.log.len() {
+                writeln!(stream, "{}", self.entry_json(i))?;
+            }
+            stream.flush()
+        }
+
+        /// Renders the entry at index i as a single line JSON object.
+        fn entry_json(&self, i: usize) -> String {
+            format!(
+                "{{\"type\":\"{}\",\"id\":{},\"message\":\"{}\",\"time\":{}}}",
+                self.log_type[i].level_name(),
+                self.log_id[i],
+                json_escape(&self.log[i]),
+                format_time(self.log_time[i])
+            )
+        }
+
+        /// Converts the logger into an Apache Arrow record batch with one row per entry.
+        /// The columns are:
+        ///  - message - Utf8, the entry's info.
+        ///  - id - UInt64, the entry's ID.
+        ///  - type - Dictionary(Int8, Utf8), the entry's level name, e.g. LOG.
+        ///
+        /// No column is nullable, since every entry has all three.
+        #[cfg(feature = "columnar")]
+        pub fn to_arrow(&self) -> RecordBatch {
+            let schema = Schema::new(vec![
+                Field::new("message", DataType::Utf8, false),
+                Field::new("id", DataType::UInt64, false),
+                Field::new(
+                    "type",
+                    DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                    false,
+                ),
+            ]);
+
+            let messages = StringArray::from_iter_values(self.log.iter());
+            let ids = UInt64Array::from_iter_values(self.log_id.iter().map(|&id| id as u64));
+            let types: DictionaryArray<Int8Type> =
+                self.log_type.iter().map(LoggingType::level_name).collect();
+
+            RecordBatch::try_new(
+                Arc::new(schema),
+                vec![Arc::new(messages), Arc::new(ids), Arc::new(types)],
+            )
+            .expect("the columns always match the schema")
+        }
+
+        /// Serializes the entries added since the previous call as a JSON array.
+        /// Each entry is an object like those sent by stream_to_tcp.
+        /// Entries removed from the logger before being serialized are skipped,
+        /// and an empty array is returned when nothing new was added.
+        pub fn to_json_incremental(&mut self) -> String {
+            let objects: Vec<String> = (self.json_cursor..self.log.len())
+                .map(|i| self.entry_json(i))
+                .collect();
+            self.json_cursor = self.log.len();
+            format!("[{}]", objects.join(","))
+        }
+
+        /// Writes every entry into the SQLite database at path, creating it if needed.
+        /// Entries are inserted in one transaction into the table
+        /// logs(id_seq INTEGER PRIMARY KEY, type TEXT, log_id INTEGER, message TEXT),
+        /// which is created if it does not exist yet.
+        /// id_seq is assigned by SQLite, so writing again appends after the existing rows.
+        /// type holds the level name, e.g. LOG.
+        #[cfg(feature = "rusqlite")]
+        pub fn write_to_sqlite<P: AsRef<Path>>(&self, path: P) -> rusqlite::Result<()> {
+            let mut connection = Connection::open(path)?;
+            let transaction = connection.transaction()?;
+            transaction.execute(
+                "CREATE TABLE IF NOT EXISTS logs (
+                    id_seq INTEGER PRIMARY KEY,
+                    type TEXT,
+                    log_id INTEGER,
+                    message TEXT
+                )",
+                [],
+            )?;
+
+            {
+                let mut insert = transaction
+                    .prepare("INSERT INTO logs (type, log_id, message) VALUES (?1, ?2, ?3)")?;
+                for i in 0..self.log.len() {
+                    insert.execute(params![
+                        self.log_type[i].level_name(),
+                        self.log_id[i] as i64,
+                        self.log[i]
+                    ])?;
+                }
+            }
+
+            transaction.commit()
+        }
+
+        /// Renders every entry as a line in the Common Log Format used by web servers:
+        /// host ident authuser [date] "request" status bytes.
+        /// host is returned by host_id_fn for each entry. The other fields are filled in as:
+        ///  - ident, authuser - Always -.
+        ///  - date - When the entry was added, in UTC.
+        ///  - request - The entry's info, with quotes and backslashes escaped.
+        ///  - status - 500 for Errors, 200 for everything else.
+        ///  - bytes - The length of the entry's info.
+        pub fn to_clf(&self, host_id_fn: impl Fn(&LogEntry) -> String) -> String {
+            let mut out = String::new();
+            for entry in self.entries() {
+                let status = if entry.log_type == LoggingType::Error {
+                    500
+                } else {
+                    200
+                };
+                out.push_str(&format!(
+                    "{} - - [{}] \"{}\" {} {}\n",
+                    host_id_fn(&entry),
+                    format_clf_time(entry.log_time),
+                    entry.log.replace('\\', "\\\\").replace('"', "\\\""),
+                    status,
+                    entry.log.len()
+                ));
+            }
+            out
+        }
+
+        /// Converts every entry into a JSON object with the same fields stream_to_tcp sends:
+        /// type, id, message and time. The objects can be extended with extra fields,
+        /// e.g. a hostname, before being serialized.
+        #[cfg(feature = "serde")]
+        pub fn to_json_maps(&self) -> Vec<Map<String, Value>> {
+            (0..self.log.len())
+                .map(|i| {
+                    let since_epoch = self.log_time[i]
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default();
+                    let mut map = Map::new();
+                    map.insert("type".to_string(), self.log_type[i].level_name().into());
+                    map.insert("id".to_string(), self.log_id[i].into());
+                    map.insert("message".to_string(), self.log[i].clone().into());
+                    map.insert(
+                        "time".to_string(),
+                        (since_epoch.as_millis() as f64 / 1000.0).into(),
+                    );
+                    map
+                })
+                .collect()
+        }
+
+        /// Converts every entry into an OpenTelemetry log record.
+        /// The info becomes the body, the ID is stored in an id attribute,
+        /// and the type maps to a severity:
+        ///  - Error - ERROR
+        ///  - Warning - WARN
+        ///  - Log - INFO
+        ///  - Marker - TRACE
+        #[cfg(feature = "otel")]
+        pub fn to_otel_records(&self) -> Vec<LogRecord> {
+            self.entries()
+                .map(|entry| {
+                    let severity = match entry.log_type {
+                        LoggingType::Error => Severity::Error,
+                        LoggingType::Warning => Severity::Warn,
+                        LoggingType::Log => Severity::Info,
+                        LoggingType::Marker => Severity::Trace,
+                    };
+                    LogRecord::builder()
+                        .with_timestamp(entry.log_time)
+                        .with_severity_number(severity)
+                        .with_severity_text(entry.log_type.level_name())
+                        .with_body(entry.log.to_string())
+                        .with_attribute("id", entry.log_id as i64)
+                        .build()
+                })
+                .collect()
+        }
+
+        /// Drops every entry after the first Error, keeping the Error itself.
+        /// Does nothing if no Error was logged.
+        pub fn truncate_at_first_error(&mut self) {
+            if let Some(first_error) = self.first_error() {
+                self.truncate_entries(first_error + 1);
+            }
+        }
+
+        /// Returns every Error along with up to window entries before and after it, in logged order.
+        /// Overlapping windows are merged, so every entry is returned at most once.
+        /// Returns an empty vector if no Error was logged.
+        pub fn context_around_errors(&self, window: usize) -> Vec<LogEntry<'_>> {
+            let mut included = vec![false; self.log.len()];
+            for (i, log_type) in self.log_type.iter().enumerate() {
+                if *log_type == LoggingType::Error {
+                    let end = i.saturating_add(window).min(self.log.len() - 1);
+                    for slot in &mut included[i.saturating_sub(window)..=end] {
+                        *slot = true;
+                    }
+                }
+            }
+            (0..self.log.len())
+                .filter(|&i| included[i])
+                .map(|i| self.entry(i))
+                .collect()
+        }
+
+        /// Returns the last Warning logged before the first Error, paired with that Error.
+        /// The Warning is often the precursor of the failure.
+        /// Returns None if no Error was logged, or no Warning came before it.
+        pub fn warning_before_error(&self) -> Option<(LogEntry<'_>, LogEntry<'_>)> {
+            let first_error = self.first_error()?;
+            let warning = self.log_type[..first_error]
+                .iter()
+                .rposition(|t| *t == LoggingType::Warning)?;
+            Some((self.entry(warning), self.entry(first_error)))
+        }
+
+        /// Returns every entry added between start and end, both inclusive, in logged order.
+        /// Every entry is timestamped when added, so no entry is ever left out for lacking a time.
+        /// Returns an empty vector if start is after end.
+        pub fn in_time_range(&self, start: SystemTime, end: SystemTime) -> Vec<LogEntry<'_>> {
+            self.entries()
+                .filter(|entry| start <= entry.log_time && entry.log_time <= end)
+                .collect()
+        }
+
+        /// Returns every entry logged after the first Error.
+        /// Since Errors are unrecoverable, these often point at code paths
+        /// that kept running when they should have returned early.
+        /// Returns an empty vector if no Error was logged.
+        pub fn entries_after_first_error(&self) -> Vec<LogEntry<'_>> {
+            match self.first_error() {
+                Some(first_error) => (first_error + 1..self.log.len())
+                    .map(|i| self.entry(i))
+                    .collect(),
+                None => Vec::new(),
+            }
+        }
+
+        /// Returns the index of the first Error.
+        fn first_error(&self) -> Option<usize> {
+            self.log_type.iter().position(|t| *t == LoggingType::Error)
+        }
+
+        /// Creates a child logger for a nested operation called name.
+        /// The child starts with a begin scope marker instead of the initial marker,
+        /// and shares the parent's template.
+        /// Once finished, hand it back with absorb_child.
+        pub fn child(&self, name: &str) -> Logger {
+            let mut child = Logger::default();
+            child.template = self.template.clone();
+            child.warn_unparsed_errors = self.warn_unparsed_errors;
+            child.push_entry(&scope_begin(name), INIT_MARK_ID, LoggingType::Marker);
+            child
+        }
+
+        /// Folds a finished child logger into this one.
+        /// The child's entries are added between a begin scope and an end scope marker,
+        /// keeping the times they were logged at.
+        /// A begin scope marker is only added if the child does not already start with one.
+        pub fn absorb_child(&mut self, name: &str, child: Logger) {
+            let begin = scope_begin(name);
+            let entries = child.into_entries();
+
+            let has_begin = entries
+                .first()
+                .is_some_and(|first| first.log_type == LoggingType::Marker && first.log == begin);
+            if !has_begin {
+                self.push_entry(&begin, INIT_MARK_ID, LoggingType::Marker);
+            }
+            for entry in &entries {
+                self.push_new(entry.as_entry(self.log.len()));
+            }
+            self.push_entry(&scope_end(name), INIT_MARK_ID, LoggingType::Marker);
+        }
+
+        /// Consumes the logger, returning every entry as an OwnedLogEntry.
+        /// Unlike entries, the result does not borrow the logger.
+        pub fn into_entries(mut self) -> Vec<OwnedLogEntry> {
+            let entries = (0..self.log.len())
+                .map(|i| OwnedLogEntry {
+                    log: mem::take(&mut self.log[i]),
+                    log_id: self.log_id[i],
+                    log_type: self.log_type[i].clone(),
+                    log_time: self.log_time[i],
+                    log_os_error: self.log_os_error[i].take(),
+                    log_context: self.log_context[i].take(),
+                    log_sequence: self.log_sequence[i],
+                    log_separator: self.log_separator[i],
+                    log_init: self.log_init[i],
+                })
+                .collect();
+
+            // The entries now live on, so dropping the emptied logger must not warn about them.
+            self.truncate_entries(0);
+            entries
+        }
+
+        /// Copies every entry into an OwnedLogEntry, leaving the logger untouched.
+        /// OwnedLogEntry is the owned form of LogEntry, so the result does not borrow the logger.
+        /// from_aos rebuilds an identical set of entries from the result.
+        pub fn to_aos(&self) -> Vec<OwnedLogEntry> {
+            self.entries().map(OwnedLogEntry::from).collect()
+        }
+
+        /// Builds a logger holding exactly entries, in order.
+        /// The returned logger has no configuration and no extra initial marker.
+        pub fn from_aos(entries: Vec<OwnedLogEntry>) -> Logger {
+            let mut logger = Logger::default();
+            logger.replace_entries(entries);
+            logger
+        }
+
+        /// Returns the amount of entries logged per second over the whole run.
+        /// The run spans from the earliest to the latest entry.
+        /// Returns 0.0 with fewer than two entries, or when the span is zero.
+        pub fn entries_per_second(&self) -> f64 {
+            let (first, last) = match (self.log_time.iter().min(), self.log_time.iter().max()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return 0.0,
+            };
+            let span = last
+                .duration_since(*first)
+                .unwrap_or_default()
+                .as_secs_f64();
+
+            if self.log.len() < 2 || span == 0.0 {
+                return 0.0;
+            }
+            self.log.len() as f64 / span
+        }
+
+        /// Finds the longest time gap between two consecutive entries.
+        /// Returns the index of the entry after the gap, and the gap itself.
+        /// On ties, the earliest gap wins.
+        /// Returns None with fewer than two entries.
+        pub fn longest_gap(&self) -> Option<(usize, Duration)> {
+            let mut longest: Option<(usize, Duration)> = None;
+            for i in 1..self.log_time.len() {
+                let gap = self.log_time[i]
+                    .duration_since(self.log_time[i - 1])
+                    .unwrap_or_default();
+                if longest.is_none_or(|(_, longest_gap)| gap > longest_gap) {
+                    longest = Some((i, gap));
+                }
+            }
+            longest
+        }
+
+        /// Converts the scope markers into folded stacks, as read by flamegraph tools.
+        /// Every closed scope becomes a parent;child line followed by its self time in microseconds,
+        /// which is its duration minus the time spent in its child scopes.
+        /// An end scope marker closes any scopes opened inside it that are still open,
+        /// and is ignored if its scope is not open.
+        /// Scopes left open are closed at the time of the last entry.
+        pub fn folded_stacks(&self) -> String {
+            let mut open: Vec<OpenScope<'_>> = Vec::new();
+            let mut out = String::new();
+
+            for i in 0..self.log.len() {
+                if self.log_type[i] != LoggingType::Marker {
+                    continue;
+                }
+
+                if let Some(name) = self.log[i].strip_prefix(SCOPE_BEGIN) {
+                    open.push((name, self.log_time[i], Duration::ZERO));
+                } else if let Some(name) = self.log[i].strip_prefix(SCOPE_END) {
+                    if let Some(depth) = open
+                        .iter()
+                        .rposition(|(open_name, _, _)| *open_name == name)
+                    {
+                        while open.len() > depth {
+                            close_scope(&mut open, self.log_time[i], &mut out);
+                        }
+                    }
+                }
+            }
+
+            if let Some(last) = self.log_time.last() {
+                while !open.is_empty() {
+                    close_scope(&mut open, *last, &mut out);
+                }
+            }
+            out
+        }
+
+        /// Encodes every entry as a fixed size record of 9 + msg_width bytes, laid out as:
+        ///  - 1 byte - The type: 0 for Error, 1 for Warning, 2 for Log, 3 for Marker.
+        ///  - 8 bytes - The ID as a little-endian u64.
+        ///  - msg_width bytes - The UTF-8 info, padded with NUL bytes.
+        ///
+        /// Info longer than msg_width bytes is truncated, which may split a multi-byte character.
+        pub fn to_fixed_records(&self, msg_width: usize) -> Vec<u8> {
+            let mut out = Vec::with_capacity(self.log.len() * (9 + msg_width));
+            for i in 0..self.log.len() {
+                let message = self.log[i].as_bytes();
+                let kept = message.len().min(msg_width);
+
+                out.push(self.log_type[i].type_byte());
+                out.extend_from_slice(&(self.log_id[i] as u64).to_le_bytes());
+                out.extend_from_slice(&message[..kept]);
+                out.resize(out.len() + msg_width - kept, 0);
+            }
+            out
+        }
+
+        /// Reclassifies entries by their info.
+        /// Each rule maps a substring to a type, and every entry whose info contains
+        /// the substring of a rule is changed to that rule's type.
+        /// When several rules match, the first one wins.
+        /// Entries matching no rule keep their type, and separators always stay Markers.
+        pub fn reclassify_by_rules(&mut self, rules: &[(&str, LoggingType)]) {
+            for i in 0..self.log.len() {
+                if self.is_separator(i) {
+                    continue;
+                }
+                let rule = rules
+                    .iter()
+                    .find(|(pattern, _)| self.log[i].contains(pattern));
+                if let Some((_, log_type)) = rule {
+                    self.log_type[i] = log_type.clone();
+                }
+            }
+        }
+
+        /// Collapses loops, where a run of min_len to max_len entries repeats back to back.
+        /// The first run is kept, and its repetitions are replaced by a Marker reading
+        /// [previous N lines repeated M times], where M counts the repetitions after the first run.
+        /// Entries repeat when their info, ID and type match, their times are ignored.
+        /// Loops are searched from the start of the log. When runs of different lengths
+        /// repeat from the same entry, the one collapsing the most entries wins,
+        /// and the shortest run breaks ties, so A B A B A B collapses to A B and a note.
+        /// A min_len of 0 is treated as 1.
+        /// Takes O(n * max_len) steps for n entries, plus the size of the loops collapsed,
+        /// so keep max_len to the longest loop worth finding on long logs.
+        pub fn collapse_loops(&mut self, min_len: usize, max_len: usize) {
+            let min_len = min_len.max(1);
+            let len = self.log.len();
+
+            // Entries are compared through a key per distinct info, ID and type.
+            let mut key_of: HashMap<(&str, usize, &LoggingType), usize> = HashMap::new();
+            let keys: Vec<usize> = (0..len)
+                .map(|i| {
+                    let next_key = key_of.len();
+                    *key_of
+                        .entry((self.log[i].as_str(), self.log_id[i], &self.log_type[i]))
+                        .or_insert(next_key)
+                })
+                .collect();
+            let same = |a: usize, b: usize| keys[a] == keys[b];
+
+            let mut kept: Vec<OwnedLogEntry> = Vec::with_capacity(len);
+            let mut i = 0;
+            while i < len {
+                // The run length and repetitions collapsing the most entries from i.
+                let mut best: Option<(usize, usize)> = None;
+                for run_len in min_len..=max_len.min((len - i) / 2) {
+                    // A run can only repeat if the entry after it starts the run again.
+                    if !same(i, i + run_len) {
+                        continue;
+                    }
+                    let mut repeats = 0;
+                    while i + (repeats + 2) * run_len <= len
+                        && (0..run_len).all(|k| same(i + k, i + (repeats + 1) * run_len + k))
+                    {
+                        repeats += 1;
+                    }
+                    let collapsed = run_len * repeats;
+                    if collapsed
+                        > best.map_or(0, |(best_len, best_repeats)| best_len * best_repeats)
+                    {
+                        best = Some((run_len, repeats));
+                    }
+                }
+
+                match best {
+                    Some((run_len, repeats)) => {
+                        let last = i + (repeats + 1) * run_len - 1;
+                        kept.extend((i..i + run_len).map(|k| OwnedLogEntry::from(self.entry(k))));
+                        kept.push(OwnedLogEntry {
+                            log: format!("[previous {} lines repeated {} times]", run_len, repeats),
+                            log_id: INIT_MARK_ID,
+                            log_type: LoggingType::Marker,
+                            log_time: self.log_time[last],
+                            log_os_error: None,
+                            log_context: None,
+                            log_sequence: self.log_sequence[last],
+                            log_separator: false,
+                            log_init: false,
+                        });
+                        i = last + 1;
+                    }
+                    None => {
+                        kept.push(OwnedLogEntry::from(self.entry(i)));
+                        i += 1;
+                    }
+                }
+            }
+
+            self.replace_entries(kept);
+        }
+
+        /// Merges every run of consecutive entries with continuation_id into a single entry,
+        /// e.g. a stack trace that was logged line by line.
+        /// The merged entry keeps everything from the first entry of the run,
+        /// except that its info is the info of every entry in the run, joined by newlines.
+        pub fn join_continuations(&mut self, continuation_id: usize) {
+            let mut kept: Vec<OwnedLogEntry> = Vec::with_capacity(self.log.len());
+            for i in 0..self.log.len() {
+                let continues = i > 0
+                    && self.log_id[i] == continuation_id
+                    && self.log_id[i - 1] == continuation_id;
+                match kept.last_mut() {
+                    Some(first) if continues => {
+                        first.log.push('\n');
+                        first.log.push_str(&self.log[i]);
+                    }
+                    _ => kept.push(OwnedLogEntry::from(self.entry(i))),
+                }
+            }
+            self.replace_entries(kept);
+        }
+
+        /// Returns whether the log ends cleanly, a heuristic for health checks.
+        /// The log ends cleanly when its last entry is a Marker and no Error was logged.
+        /// Separators are skipped, so a trailing separator does not count as the final Marker.
+        /// Pair it with the convention of adding a final Marker once your program completes.
+        /// Note that a logger holding only its initial marker also ends cleanly.
+        pub fn ended_cleanly(&self) -> bool {
+            let last = (0..self.log.len()).rev().find(|&i| !self.is_separator(i));
+            last.is_some_and(|i| self.log_type[i] == LoggingType::Marker)
+                && !self.log_type.contains(&LoggingType::Error)
+        }
+
+        /// Returns whether anything besides the initial marker was logged.
+        /// Initial markers are recognized by how they were added, not by their info,
+        /// so they are skipped even after rebase_ids, reclassify_by_rules or aggregate.
+        /// Useful in tests, to check that instrumented code actually ran.
+        pub fn logged_anything(&self) -> bool {
+            (0..self.log.len()).any(|i| !self.is_init(i))
+        }
+
+        /// Returns how many distinct IDs were logged, e.g. how many log sites fired.
+        /// The IDs of the initial markers added by new_logger and of separators are not counted,
+        /// but every other entry's is, including Markers added with no ID.
+        pub fn distinct_id_count(&self) -> usize {
+            self.logged_indices()
+                .map(|i| self.log_id[i])
+                .collect::<HashSet<_>>()
+                .len()
+        }
+
+        /// Compares the content of two runs, e.g. before and after a fix.
+        /// Entries are grouped by type and info, then listed in three sections,
+        /// each in the order the groups were first logged:
+        ///  - only in before - With how many times they were logged.
+        ///  - only in after - With how many times they were logged.
+        ///  - in both - With both counts and the change between them.
+        ///
+        /// The initial marker and separators are left out, since every run has them.
+        pub fn compare_runs(before: &Logger, after: &Logger) -> String {
+            let before_counts = before.message_counts();
+            let after_counts = after.message_counts();
+            let count_in = |counts: &[((&str, &str), usize)], key: &(&str, &str)| {
+                counts
+                    .iter()
+                    .find(|(counted, _)| counted == key)
+                    .map(|(_, count)| *count)
+            };
+
+            let mut report = String::from("only in before:\n");
+            for (key, count) in &before_counts {
+                if count_in(&after_counts, key).is_none() {
+                    report.push_str(&format!("  [{}]: {} (x{})\n", key.0, key.1, count));
+                }
+            }
+            report.push_str("only in after:\n");
+            for (key, count) in &after_counts {
+                if count_in(&before_counts, key).is_none() {
+                    report.push_str(&format!("  [{}]: {} (x{})\n", key.0, key.1, count));
+                }
+            }
+            report.push_str("in both:\n");
+            for (key, count) in &before_counts {
+                if let Some(after_count) = count_in(&after_counts, key) {
+                    report.push_str(&format!(
+                        "  [{}]: {} ({} -> {}, {:+})\n",
+                        key.0,
+                        key.1,
+                        count,
+                        after_count,
+                        after_count as i64 - *count as i64
+                    ));
+                }
+            }
+            report
+        }
+
+        /// Counts how many times each distinct type and info pair was logged,
+        /// for a report of the most recurring issues.
+        /// Sorted by count, highest first, then by severity, Errors first, then by info.
+        /// The initial marker and separators are not counted.
+        pub fn summarize_by_message(&self) -> Vec<(LoggingType, String, usize)> {
+            let mut tally: HashMap<(&LoggingType, &str), usize> = HashMap::new();
+            for i in self.logged_indices() {
+                *tally
+                    .entry((&self.log_type[i], self.log[i].as_str()))
+                    .or_insert(0) += 1;
+            }
+
+            let mut summary: Vec<(LoggingType, String, usize)> = tally
+                .into_iter()
+                .map(|((log_type, log), count)| (log_type.clone(), log.to_string(), count))
+                .collect();
+            summary.sort_by(|a, b| {
+                b.2.cmp(&a.2)
+                    .then(a.0.type_byte().cmp(&b.0.type_byte()))
+                    .then(a.1.cmp(&b.1))
+            });
+            summary
+        }
+
+        /// Counts the logged entries by level name and info, in the order they were first logged.
+        /// The initial marker and separators are not counted.
+        fn message_counts(&self) -> Vec<((&'static str, &str), usize)> {
+            let mut counts: Vec<((&'static str, &str), usize)> = Vec::new();
+            for i in self.logged_indices() {
+                let key = (self.log_type[i].level_name(), self.log[i].as_str());
+                match counts.iter_mut().find(|(counted, _)| *counted == key) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((key, 1)),
+                }
+            }
+            counts
+        }
+
+        /// Returns the indices of every entry whose info is longer than max_len bytes.
+        /// Useful for enforcing a message size contract, like LoggerNS's MAX_LOG_LENGTH.
+        pub fn find_oversized(&self, max_len: usize) -> Vec<usize> {
+            (0..self.log.len())
+                .filter(|&i| self.log[i].len() > max_len)
+                .collect()
+        }
+
+        /// Asserts that the logged entries match expected exactly, in order.
+        /// Only types and info are compared. IDs, the initial marker and separators are ignored.
+        /// On a mismatch, panics showing the first mismatching position,
+        /// along with both sequences around it.
+        pub fn assert_sequence(&self, expected: &[(LoggingType, &str)]) {
+            let actual: Vec<(LoggingType, &str)> = self
+                .logged_indices()
+                .map(|i| (self.log_type[i].clone(), self.log[i].as_str()))
+                .collect();
+
+            let mismatch =
+                (0..actual.len().max(expected.len())).find(|&i| actual.get(i) != expected.get(i));
+            let position = match mismatch {
+                Some(position) => position,
+                None => return,
+            };
+
+            let describe = |sequence: &[(LoggingType, &str)]| {
+                let mut out = String::new();
+                let start = position.saturating_sub(SEQUENCE_CONTEXT);
+                let end = position + SEQUENCE_CONTEXT + 1;
+                for (i, (log_type, log)) in sequence.iter().enumerate().take(end).skip(start) {
+                    let pointer = if i == position { ">" } else { " " };
+                    out.push_str(&format!(
+                        "{} {}: [{}] {}\n",
+                        pointer,
+                        i,
+                        log_type.level_name(),
+                        log
+                    ));
+                }
+                if position >= sequence.len() {
+                    out.push_str(&format!("> {}: <end>\n", position));
+                }
+                out
+            };
+
+            panic!(
+                "log sequence mismatch at position {} (expected {} entries, logged {})\nexpected:\n{}logged:\n{}",
+                position,
+                expected.len(),
+                actual.len(),
+                describe(expected),
+                describe(&actual)
+            );
+        }
+
+        /// Groups consecutive entries of the same type into runs, keeping their order.
+        /// E.g. 5 Logs, then 2 Warnings, then 3 Logs make three runs.
+        pub fn runs(&self) -> Vec<(LoggingType, Vec<LogEntry<'_>>)> {
+            let mut runs: Vec<(LoggingType, Vec<LogEntry<'_>>)> = Vec::new();
+            for entry in self.entries() {
+                match runs.last_mut() {
+                    Some((log_type, run)) if *log_type == entry.log_type => run.push(entry),
+                    _ => runs.push((entry.log_type.clone(), vec![entry])),
+                }
+            }
+            runs
+        }
+
+        /// Renders one character per entry, giving the shape of a run at a glance:
+        ///  - Error - E
+        ///  - Warning - W
+        ///  - Log - .
+        ///  - Marker - |
+        ///
+        /// Separators are skipped.
+        pub fn to_sparkline(&self) -> String {
+            (0..self.log.len())
+                .filter(|&i| !self.is_separator(i))
+                .map(|i| match self.log_type[i] {
+                    LoggingType::Error => 'E',
+                    LoggingType::Warning => 'W',
+                    LoggingType::Log => '.',
+                    LoggingType::Marker => '|',
+                })
+                .collect()
+        }
+
+        /// Renumbers every entry's ID sequentially in the order they were added, starting at start.
+        /// Returns the next free ID, so rebases of several loggers can be chained
+        /// to give each one its own range before merging them.
+        /// IDs saturate at usize::MAX, so near the limit the last entries share usize::MAX,
+        /// which is then also returned.
+        pub fn rebase_ids(&mut self, start: usize) -> usize {
+            for (offset, log_id) in self.log_id.iter_mut().enumerate() {
+                *log_id = start.saturating_add(offset);
+            }
+            start.saturating_add(self.log_id.len())
+        }
+
+        /// Builds a tree of the entries, where parent_fn maps an ID to the ID of its parent.
+        /// Each entry is nested under the closest entry before it that has its parent's ID.
+        /// Entries are kept at the root when parent_fn returns None, when no earlier entry
+        /// has the parent's ID, or when following parent_fn from their ID leads back to it.
+        pub fn to_tree<F: Fn(usize) -> Option<usize>>(&self, parent_fn: F) -> LogTree {
+            let in_cycle = |id: usize| {
+                let mut seen = HashSet::new();
+                let mut current = parent_fn(id);
+                while let Some(ancestor) = current {
+                    if ancestor == id {
+                        return true;
+                    }
+                    if !seen.insert(ancestor) {
+                        return false;
+                    }
+                    current = parent_fn(ancestor);
+                }
+                false
+            };
+
+            let mut children: Vec<Vec<usize>> = vec![Vec::new(); self.log.len()];
+            let mut roots = Vec::new();
+            let mut last_with_id: HashMap<usize, usize> = HashMap::new();
+            for i in 0..self.log.len() {
+                let id = self.log_id[i];
+                let parent = parent_fn(id)
+                    .filter(|_| !in_cycle(id))
+                    .and_then(|parent_id| last_with_id.get(&parent_id));
+                match parent {
+                    Some(&parent) => children[parent].push(i),
+                    None => roots.push(i),
+                }
+                last_with_id.insert(id, i);
+            }
+
+            // Parents always come before their children, so building from the back
+            // finishes every child before its parent needs it.
+            let mut nodes: Vec<Option<LogTreeNode>> = vec![None; self.log.len()];
+            for i in (0..self.log.len()).rev() {
+                let node_children = children[i]
+                    .iter()
+                    .map(|&child| nodes[child].take().expect("each child has one parent"))
+                    .collect();
+                nodes[i] = Some(LogTreeNode {
+                    entry: OwnedLogEntry::from(self.entry(i)),
+                    children: node_children,
+                    line: self.render_line(i),
+                });
+            }
+
+            LogTree {
+                roots: roots
+                    .iter()
+                    .map(|&root| nodes[root].take().expect("roots have no parent"))
+                    .collect(),
+            }
+        }
+
+        /// Returns every entry keyed by its ID.
+        /// Intended for loggers whose IDs are unique. When several entries share an ID,
+        /// the last one added is kept.
+        pub fn by_id_map(&self) -> HashMap<usize, LogEntry<'_>> {
+            self.entries().map(|entry| (entry.log_id, entry)).collect()
+        }
+
+        /// Returns the indices of every logged entry, in order,
+        /// skipping the initial markers added by new_logger and separators.
+        fn logged_indices(&self) -> impl Iterator<Item = usize> + '_ {
+            (0..self.log.len()).filter(move |&i| !self.is_init(i) && !self.is_separator(i))
+        }
+
+        /// Returns whether the entry at index i is the initial marker added by new_logger.
+        fn is_init(&self, i: usize) -> bool {
+            self.log_init[i]
+        }
+
+        /// Returns whether the entry at index i was added by add_separator.
+        fn is_separator(&self, i: usize) -> bool {
+            self.log_separator[i]
+        }
+
+        /// Returns the entry at index i.
+        fn entry(&self, i: usize) -> LogEntry<'_> {
+            LogEntry {
+                index: i,
+                log: &self.log[i],
+                log_id: self.log_id[i],
+                log_type: self.log_type[i].clone(),
+                log_time: self.log_time[i],
+                log_os_error: self.log_os_error[i]
+                    .as_ref()
+                    .map(|(code, desc)| (*code, desc.as_str())),
+                log_context: self.log_context[i].as_deref(),
+                log_sequence: self.log_sequence[i],
+                log_separator: self.log_separator[i],
+                log_init: self.log_init[i],
+            }
+        }
+
+        /// Iterates over every entry in the order they were added.
+        pub fn entries(&self) -> impl Iterator<Item = LogEntry<'_>> {
+            (0..self.log.len()).map(move |i| self.entry(i))
+        }
+
+        /// Returns the entries between two markers.
+        /// Starts after the first Marker whose info is start_marker,
+        /// and stops before the next Marker whose info is end_marker.
+        /// Both markers are excluded from the result.
+        /// If either marker is missing, an empty vector is returned.
+        pub fn between_markers(&self, start_marker: &str, end_marker: &str) -> Vec<LogEntry<'_>> {
+            let is_marker = |i: &usize, info: &str| {
+                self.log_type[*i] == LoggingType::Marker && self.log[*i] == info
+            };
+
+            let start = match (0..self.log.len()).find(|i| is_marker(i, start_marker)) {
+                Some(start) => start,
+                None => return Vec::new(),
+            };
+            let end = match (start + 1..self.log.len()).find(|i| is_marker(i, end_marker)) {
+                Some(end) => end,
+                None => return Vec::new(),
+            };
+
+            (start + 1..end).map(|i| self.entry(i)).collect()
+        }
+
+        /// Adds a new Error to your logger, capturing the last OS error alongside it.
+        /// The raw OS error code and its description are printed after the Error,
+        /// e.g. (os error 2: No such file or directory).
+        pub fn add_os_error(&mut self, log: &str, log_id: usize) {
+            let os_error = io::Error::last_os_error();
+            let code = os_error.raw_os_error().unwrap_or(0);
+            let text = os_error.to_string();
+            let suffix = format!(" (os error {})", code);
+            let desc = text.strip_suffix(&suffix).unwrap_or(&text);
+
+            let log_type = self.severity_for(log_id, LoggingType::Error);
+            self.push_added(log, log_id, log_type, Some((code, desc)), false);
+        }
+
+        /// Drains an iterator of Results into your logger.
+        /// Every Err is added as an Error using its Display output and err_id.
+        /// Once drained, a Log with ok_id records how many Ok's were seen.
+        /// Returns the amount of Ok's.
+        pub fn log_results<T, E: Display, I: Iterator<Item = Result<T, E>>>(
             &mut self,
-            message: [c_char; MAX_LOG_ENTRIES],
-            id: usize,
-        ) -> Result<usize, OverflowError> {
-            if let Some(slot) = self.get_next_avaliable_slot() {
-                self.log[slot] = message;
-                self.log_id[slot] = id;
-                self.log_type[slot] = LoggingTypeNS::Log;
-                Ok(slot)
-            } else {
-                Err(OverflowError::BufferEntryOverflow)
+            iter: I,
+            ok_id: usize,
+            err_id: usize,
+        ) -> usize {
+            let mut ok_count = 0;
+            for result in iter {
+                match result {
+                    Ok(_) => ok_count += 1,
+                    Err(err) => self.add_error(&err.to_string(), err_id),
+                }
+            }
+            self.add_log(&format!("Results Succeeded: {}", ok_count), ok_id);
+            ok_count
+        }
+
+        /// Sets whether each entry is printed prefixed by its sequence number, e.g. #42.
+        /// Sequence numbers are assigned when entries are added, from a counter shared by every
+        /// logger on every thread, so they order entries even after loggers are merged.
+        /// Disabled by default.
+        pub fn set_show_sequence(&mut self, show_sequence: bool) {
+            self.show_sequence = show_sequence;
+        }
+
+        /// Sets whether control characters, e.g. \r or the ESC of an ANSI code,
+        /// are rendered as \xNN escapes so they cannot corrupt the terminal.
+        /// Only the output is escaped, the stored entries are left unchanged. Disabled by default.
+        pub fn set_escape_control(&mut self, escape_control: bool) {
+            self.escape_control = escape_control;
+        }
+
+        /// Sets the names printed alongside IDs, e.g. Info ID: 1 (startup).
+        /// IDs without a name are printed as before. Stored IDs are left unchanged.
+        pub fn set_id_names(&mut self, map: HashMap<usize, String>) {
+            self.id_names = map;
+        }
+
+        /// Sets the context ID captured by every entry added from now on, e.g. a request ID.
+        /// Entries that were already added keep the context they were added with.
+        /// The context is printed after the ID as "; Context: ctx", or with {ctx} in a template.
+        pub fn set_context_id(&mut self, ctx: &str) {
+            self.context = Some(ctx.to_string());
+        }
+
+        /// Stops capturing a context ID for entries added from now on.
+        pub fn clear_context_id(&mut self) {
+            self.context = None;
+        }
+
+        /// Sets the template used to render the prefix of every entry.
+        /// Unknown placeholders are rendered literally.
+        /// If the template does not contain {msg}, the message follows the prefix after a space.
+        /// Supported placeholders:
+        ///  - {time} - Seconds since the UNIX epoch the entry was added, with milliseconds.
+        ///  - {level} - MARKER, LOG, WARNING or ERROR.
+        ///  - {id} - The entry's ID, followed by its name if set_id_names gave it one.
+        ///  - {msg} - The entry's message.
+        ///  - {ctx} - The context ID set when the entry was added, or nothing if none was set.
+        pub fn set_template(&mut self, tmpl: &str) {
+            self.template = Some(tmpl.to_string());
+        }
+
+        /// Renders the entry at index i as a single line.
+        fn render_line(&self, i: usize) -> String {
+            self.render_line_as(i, false)
+        }
+
+        /// Renders the entry at index i as a single line.
+        /// When canonical, volatile fields are replaced by placeholders such as <TIME>.
+        fn render_line_as(&self, i: usize, canonical: bool) -> String {
+            if self.is_separator(i) {
+                return SEPARATOR_MARK.to_string();
+            }
+
+            let mut line = self.render_body(i, canonical);
+            if let Some((code, desc)) = &self.log_os_error[i] {
+                line.push_str(&format!(" (os error {}: {})", code, desc));
+            }
+            if self.show_sequence {
+                line = if canonical {
+                    format!("#<SEQ> {}", line)
+                } else {
+                    format!("#{} {}", self.log_sequence[i], line)
+                };
+            }
+            if self.escape_control {
+                line = escape_control(&line);
+            }
+            line
+        }
+
+        /// Renders the entry at index i using the template, or the default format.
+        fn render_body(&self, i: usize, canonical: bool) -> String {
+            if let Some(tmpl) = &self.template {
+                let line = self.expand_template(tmpl, i, canonical);
+                if tmpl.contains("{msg}") {
+                    return line;
+                }
+                return format!("{} {}", line, self.log[i]);
+            }
+
+            let line = match self.log_type[i] {
+                LoggingType::Marker => format!("[MARKER]: {}", self.log[i]),
+                LoggingType::Log => {
+                    format!(
+                        "[LOG]: Info: {}; Info ID: {}",
+                        self.log[i],
+                        self.id_label(i)
+                    )
+                }
+                LoggingType::Warning => format!(
+                    "[WARNING]: Warning: {}; Warning ID: {}",
+                    self.log[i],
+                    self.id_label(i)
+                ),
+                LoggingType::Error => format!(
+                    "[ERROR]: Error: {}; Error ID: {}",
+                    self.log[i],
+                    self.id_label(i)
+                ),
+            };
+
+            match &self.log_context[i] {
+                Some(ctx) => format!("{}; Context: {}", line, ctx),
+                None => line,
+            }
+        }
+
+        /// Returns the ID of entry i, followed by its name in parentheses if it has one.
+        fn id_label(&self, i: usize) -> String {
+            match self.id_names.get(&self.log_id[i]) {
+                Some(name) => format!("{} ({})", self.log_id[i], name),
+                None => self.log_id[i].to_string(),
+            }
+        }
+
+        /// Substitutes every known placeholder in tmpl with the values of entry i.
+        fn expand_template(&self, tmpl: &str, i: usize, canonical: bool) -> String {
+            let mut out = String::with_capacity(tmpl.len());
+            let mut rest = tmpl;
+
+            while let Some(open) = rest.find('{') {
+                out.push_str(&rest[..open]);
+                let after = &rest[open..];
+                let close = match after.find('}') {
+                    Some(close) => close,
+                    None => {
+                        out.push_str(after);
+                        return out;
+                    }
+                };
+                match &after[1..close] {
+                    "time" if canonical => out.push_str("<TIME>"),
+                    "time" => out.push_str(&format_time(self.log_time[i])),
+                    "level" => out.push_str(self.log_type[i].level_name()),
+                    "id" => out.push_str(&self.id_label(i)),
+                    "msg" => out.push_str(&self.log[i]),
+                    "ctx" => out.push_str(self.log_context[i].as_deref().unwrap_or("")),
+                    _ => out.push_str(&after[..=close]),
+                }
+                rest = &after[close + 1..];
+            }
+
+            out.push_str(rest);
+            out
+        }
+
+        /// Renders every entry like render, but in a stable form for snapshot tests.
+        /// Volatile fields are normalized, so times become <TIME> and sequence numbers <SEQ>,
+        /// and trailing whitespace is stripped from every line.
+        pub fn canonical(&self) -> String {
+            let mut out = String::new();
+            for i in 0..self.log.len() {
+                out.push_str(self.render_line_as(i, true).trim_end());
+                out.push('\n');
             }
+            out
         }
 
-        /// Adds a Warning to your Logger.
-        /// Inputs: [c_char; 1024], usize
-        pub fn add_warning(
-            &mut self,
-            message: [c_char; 1024],
-            id: usize,
-        ) -> Result<usize, OverflowError> {
-            if let Some(slot) = self.get_next_avaliable_slot() {
-                self.log[slot] = message;
-                self.log_id[slot] = id;
-                self.log_type[slot] = LoggingTypeNS::Warning;
-                Ok(slot)
-            } else {
-                Err(OverflowError::BufferEntryOverflow)
-            }
-        }
+        /// Renders the entry at index as a single line, formatted as render would.
+        /// Returns None if index is out of range.
+        pub fn render_entry(&self, index: usize) -> Option<String> {
+            if index < self.log.len() {
+                Some(self.render_line(index))
+            } else {
+                None
+            }
+        }
+
+        /// Renders every entry into a String, one line per entry.
+        /// Unlike parse_logger, this never panics.
+        pub fn render(&self) -> String {
+            let mut out = String::new();
+            for i in 0..self.log.len() {
+                out.push_str(&self.render_line(i));
+                out.push('\n');
+            }
+            out
+        }
+
+        /// Prints every entry, grouping consecutive lines bound for the same stream,
+        /// and flushing each group before the next one is written.
+        fn print_ordered(&self) {
+            let stdout = io::stdout();
+            let stderr = io::stderr();
+            self.print_ordered_to(&mut stdout.lock(), &mut stderr.lock());
+        }
+
+        /// Writes every entry like print_ordered, with out and err standing in for stdout and stderr.
+        fn print_ordered_to<O: Write, E: Write>(&self, out: &mut O, err: &mut E) {
+            let mut runs: Vec<(bool, String)> = Vec::new();
+            for i in 0..self.log.len() {
+                let on_stderr = self.log_type[i].uses_stderr();
+                let line = self.render_line(i);
+                match runs.last_mut() {
+                    Some((run_on_stderr, run)) if *run_on_stderr == on_stderr => {
+                        run.push_str(&line);
+                        run.push('\n');
+                    }
+                    _ => runs.push((on_stderr, line + "\n")),
+                }
+            }
+
+            for (on_stderr, run) in runs {
+                // Like a closed terminal, failing to print is not worth crashing over.
+                if on_stderr {
+                    let _ = err.write_all(run.as_bytes()).and_then(|_| err.flush());
+                } else {
+                    let _ = out.write_all(run.as_bytes()).and_then(|_| out.flush());
+                }
+            }
+        }
+
+        /// Parses the Logger
+        /// Behavior with the following:
+        /// A Marker - Prints out the Marker Info.
+        /// A Log - Prints out the Log Info and Log ID.
+        /// A Warning - Error Prints the Warning Info and Warning ID.
+        /// An Error - Error Prints the Error Info and Error ID, then panics.
+        pub fn parse_logger(&self) {
+            self.parsed.set();
+
+            if self.ordered_output {
+                self.print_ordered();
+            } else {
+                for i in 0..self.log.len() {
+                    if self.log_type[i].uses_stderr() {
+                        eprintln!("{}", self.render_line(i))
+                    } else {
+                        println!("{}", self.render_line(i))
+                    }
+                }
+            }
+
+            self.panic_on_error();
+        }
+
+        /// Parses the Logger like parse_logger, but as one uninterrupted dump.
+        /// Every synchronized parse, from any thread and any logger, holds a shared output lock
+        /// while printing, so concurrent dumps never interleave.
+        /// Lines are printed in their logged order, as with set_ordered_output.
+        pub fn parse_logger_synchronized(&self) {
+            {
+                // A parse that panicked still printed everything, so a poisoned lock is fine to reuse.
+                let _guard = OUTPUT_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+                self.parsed.set();
+                self.print_ordered();
+            }
+
+            self.panic_on_error();
+        }
+
+        /// Panics with the last Error, if any Error was logged.
+        fn panic_on_error(&self) {
+            let last_error = self.log_type.iter().rposition(|t| *t == LoggingType::Error);
+            if let Some(idx) = last_error {
+                panic!(
+                    "[ERROR]: Final Error: Error: {}; Error ID: {}",
+                    self.log[idx], self.log_id[idx]
+                )
+            }
+        }
+    }
+
+    impl PartialEq for Logger {
+        /// Compares the entries and configuration.
+        /// Output bookkeeping, i.e. whether the logger was parsed and how far it was
+        /// flushed or serialized, is ignored, so outputting a logger never changes equality.
+        fn eq(&self, other: &Self) -> bool {
+            let Logger {
+                log,
+                log_id,
+                log_type,
+                log_time,
+                log_os_error,
+                log_context,
+                log_sequence,
+                log_separator,
+                log_init,
+                context,
+                template,
+                spill_path,
+                spill_max,
+                spill_error: _,
+                sink_path,
+                flush_cursor: _,
+                json_cursor: _,
+                clear_on_flush,
+                autoflush_every,
+                adds_since_flush: _,
+                autoflush_error: _,
+                parsed: _,
+                warn_unparsed_errors,
+                ordered_output,
+                id_names,
+                show_sequence,
+                ci_summary_prefix,
+                severity_overrides,
+                counters,
+                escape_control,
+            } = self;
+
+            *log == other.log
+                && *log_id == other.log_id
+                && *log_type == other.log_type
+                && *log_time == other.log_time
+                && *log_os_error == other.log_os_error
+                && *log_context == other.log_context
+                && *log_sequence == other.log_sequence
+                && *log_separator == other.log_separator
+                && *log_init == other.log_init
+                && *context == other.context
+                && *template == other.template
+                && *spill_path == other.spill_path
+                && *spill_max == other.spill_max
+                && *sink_path == other.sink_path
+                && *clear_on_flush == other.clear_on_flush
+                && *autoflush_every == other.autoflush_every
+                && *warn_unparsed_errors == other.warn_unparsed_errors
+                && *ordered_output == other.ordered_output
+                && *id_names == other.id_names
+                && *show_sequence == other.show_sequence
+                && *ci_summary_prefix == other.ci_summary_prefix
+                && *severity_overrides == other.severity_overrides
+                && *counters == other.counters
+                && *escape_control == other.escape_control
+        }
+    }
+
+    impl Drop for Logger {
+        /// Warns on stderr if Errors were logged, but parse_logger or a flush never output them.
+        /// Without this, a forgotten parse_logger lets the program exit successfully despite the Errors.
+        /// This never panics, since Drop must not unwind.
+        fn drop(&mut self) {
+            if !self.warn_unparsed_errors || self.parsed.get() {
+                return;
+            }
+
+            let errors = self
+                .log_type
+                .iter()
+                .filter(|t| **t == LoggingType::Error)
+                .count();
+            if errors != 0 {
+                // Like a closed terminal, failing to warn is not worth crashing over.
+                let _ = writeln!(
+                    io::stderr(),
+                    "[WARNING]: Logger dropped with {} Error(s) that were never parsed or flushed",
+                    errors
+                );
+            }
+        }
+    }
+
+    /// Held by parse_logger_synchronized while printing.
+    static OUTPUT_LOCK: Mutex<()> = Mutex::new(());
+
+    /// The sequence number given to the next entry added to any logger.
+    static NEXT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+    /// Returns a new sequence number, greater than every one returned before.
+    fn next_sequence() -> u64 {
+        NEXT_SEQUENCE.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// The amount of entries shown on each side of a mismatch by assert_sequence.
+    const SEQUENCE_CONTEXT: usize = 2;
+
+    /// The start of the Marker info that opens a scope.
+    const SCOPE_BEGIN: &str = "begin scope: ";
+    /// The start of the Marker info that closes a scope.
+    const SCOPE_END: &str = "end scope: ";
+
+    /// Returns the Marker info that opens the scope called name.
+    fn scope_begin(name: &str) -> String {
+        format!("{}{}", SCOPE_BEGIN, name)
+    }
+
+    /// Returns the Marker info that closes the scope called name.
+    fn scope_end(name: &str) -> String {
+        format!("{}{}", SCOPE_END, name)
+    }
+
+    /// A scope that has begun but not ended: its name, when it began,
+    /// and how long its already closed children took.
+    type OpenScope<'a> = (&'a str, SystemTime, Duration);
+
+    /// Closes the innermost open scope at end, writing its folded stack line to out.
+    fn close_scope(open: &mut Vec<OpenScope<'_>>, end: SystemTime, out: &mut String) {
+        let stack: Vec<&str> = open.iter().map(|(name, _, _)| *name).collect();
+        let (_, begin, children) = match open.pop() {
+            Some(scope) => scope,
+            None => return,
+        };
+
+        let total = end.duration_since(begin).unwrap_or_default();
+        out.push_str(&format!(
+            "{} {}\n",
+            stack.join(";"),
+            total.saturating_sub(children).as_micros()
+        ));
+
+        if let Some((_, _, parent_children)) = open.last_mut() {
+            *parent_children += total;
+        }
+    }
+
+    /// Escapes text for use inside a JSON string.
+    fn json_escape(text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    /// Replaces every control character in line with its \xNN escape.
+    fn escape_control(line: &str) -> String {
+        let mut out = String::with_capacity(line.len());
+        for c in line.chars() {
+            if c.is_control() {
+                out.push_str(&format!("\\x{:02x}", c as u32));
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// Formats a SystemTime as a Common Log Format date in UTC, e.g. 10/Oct/2000:13:55:36 +0000.
+    fn format_clf_time(time: SystemTime) -> String {
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+
+        let secs = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+        // Converts days since the epoch into a civil date, counting years from March
+        // so the leap day falls at the end of each 400 year era.
+        let shifted = days + 719_468;
+        let era = shifted / 146_097;
+        let day_of_era = shifted % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = (month_from_march + 2) % 12;
+        let year = year_of_era + era * 400 + if month < 2 { 1 } else { 0 };
+
+        format!(
+            "{:02}/{}/{}:{:02}:{:02}:{:02} +0000",
+            day,
+            MONTHS[month as usize],
+            year,
+            secs_of_day / 3600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60
+        )
+    }
+
+    /// Keeps only the items of items whose flag in keep is set.
+    fn retain_by<T>(items: &mut Vec<T>, keep: &[bool]) {
+        let mut flags = keep.iter();
+        items.retain(|_| flags.next().copied().unwrap_or(true));
+    }
+
+    /// Splits err into its kind and message, which unlike io::Error can be cloned.
+    fn error_parts(err: &io::Error) -> (io::ErrorKind, String) {
+        (err.kind(), err.to_string())
+    }
+
+    /// Formats a SystemTime as seconds since the UNIX epoch, with milliseconds.
+    fn format_time(time: SystemTime) -> String {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        format!(
+            "{}.{:03}",
+            since_epoch.as_secs(),
+            since_epoch.subsec_millis()
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::cell::RefCell;
+
+        /// A writer recording what was written between each flush.
+        struct FlushRecorder<'a> {
+            stream: &'static str,
+            pending: Vec<u8>,
+            flushed: &'a RefCell<Vec<(&'static str, String)>>,
+        }
+
+        impl Write for FlushRecorder<'_> {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.pending.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                let run = String::from_utf8(mem::take(&mut self.pending)).unwrap();
+                self.flushed.borrow_mut().push((self.stream, run));
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn ordered_output_test() {
+            let mut logger = Logger::new_logger();
+            logger.add_log(TEST_LOG, TEST_LOG_ID);
+            logger.add_warning(TEST_WARN, TEST_WARN_ID);
+            logger.add_warning(TEST_WARN, TEST_WARN_ID);
+            logger.add_log(TEST_LOG, TEST_LOG_ID);
+
+            let flushed = RefCell::new(Vec::new());
+            let mut out = FlushRecorder {
+                stream: "out",
+                pending: Vec::new(),
+                flushed: &flushed,
+            };
+            let mut err = FlushRecorder {
+                stream: "err",
+                pending: Vec::new(),
+                flushed: &flushed,
+            };
+            logger.print_ordered_to(&mut out, &mut err);
+
+            let line = |i| logger.render_line(i) + "\n";
+            assert_eq!(
+                flushed.into_inner(),
+                vec![
+                    ("out", line(0) + &line(1)),
+                    ("err", line(2) + &line(3)),
+                    ("out", line(4)),
+                ]
+            );
+        }
+
+        #[test]
+        fn shrink_to_fit_test() {
+            let mut logger = Logger::new_logger();
+            for _ in 0..100 {
+                logger.add_log(TEST_LOG, TEST_LOG_ID);
+            }
+            logger.truncate_entries(3);
+
+            let before = logger.clone();
+            logger.shrink_to_fit();
+            assert_eq!(logger, before);
+
+            let capacities = [
+                (logger.log.capacity(), logger.log.len()),
+                (logger.log_id.capacity(), logger.log_id.len()),
+                (logger.log_type.capacity(), logger.log_type.len()),
+                (logger.log_time.capacity(), logger.log_time.len()),
+                (logger.log_os_error.capacity(), logger.log_os_error.len()),
+                (logger.log_context.capacity(), logger.log_context.len()),
+                (logger.log_sequence.capacity(), logger.log_sequence.len()),
+                (logger.log_separator.capacity(), logger.log_separator.len()),
+                (logger.log_init.capacity(), logger.log_init.len()),
+            ];
+            assert!(capacities.iter().all(|&(capacity, len)| capacity == len));
+        }
+
+        #[test]
+        fn repair_desynced_test() {
+            let mut logger = Logger::new_logger();
+            logger.add_log(TEST_LOG, TEST_LOG_ID);
+            logger.log_id.push(TEST_LOG_ID);
+            logger.log_id.push(TEST_LOG_ID);
+            assert!(!logger.invariant_holds());
+
+            assert_eq!(logger.repair(), 2);
+            assert!(logger.invariant_holds());
+            assert_eq!(logger.entries().count(), 2);
+            assert_eq!(logger.repair(), 0);
+        }
+    }
+}
+
+/// The no_std implementation of std_logger
+// #[cfg(feature = "no_std")]
+pub mod no_std_logger {
+    use core::{ffi::c_char, option::Option, result::Result, usize};
+
+    /// Enum for Overflow Errors
+    /// Implements Debug for debugging
+    #[derive(Debug)]
+    pub enum OverflowError {
+        /// Enum Type for when a Log is over it's expected length.
+        BufferEntryOverflow,
+        /// Enum Type for when accessing beyond the length of an array.
+        InvalidIndex(usize),
+    }
+
+    /// The maximum amount of Log's that can be made.
+    pub const MAX_LOG_ENTRIES: usize = 1024;
+    /// The maximum length for a Log
+    pub const MAX_LOG_LENGTH: usize = 1024;
+
+    /// The no_std implementation of LoggingType
+    /// This does not implement any confort types.
+    /// However, it does implement Copy and Clone for easy initalization.
+    #[derive(Copy, Clone)]
+    pub enum LoggingTypeNS {
+        /// Error - For UNRECOVERABLE Error
+        /// WARNING! Once the parse_logger_ns function finishes parsing,
+        /// it will crash the program if it includes this type.
+        /// This is intentional behavior.
+        Error,
+        /// Warning - Used for potentially hazardous behavior logging.
+        Warning,
+        /// Log - Used for basic information logging.
+        Log,
+        /// Marker - Used for marking when a certain point in a program happens.
+        Marker,
+    }
+
+    pub struct LoggerNS {
+        /// log - An array of an array of 1024 c_char's of 1024 arrays
+        /// To better describe it, 1024 arrays can be put into this array.
+        /// Inside those arrays, 1024 c_char's can be put into it.
+        log: [[c_char; MAX_LOG_LENGTH]; MAX_LOG_ENTRIES],
+        /// log_id - An array of 1024 usize's
+        log_id: [usize; MAX_LOG_ENTRIES],
+        /// log_type - An array of 1024 LoggingTypeNS's
+        log_type: [LoggingTypeNS; MAX_LOG_ENTRIES],
+        /// free_slots - an array of 1024 booleans for constant time lookup
+        // TODO: Replace this with a u16 counter for 0(1) lookup time.
+        free_slots: [bool; MAX_LOG_ENTRIES],
+        /// strict_nul - Whether parsing rejects messages with data after an interior NUL.
+        strict_nul: bool,
+    }
+
+    impl LoggerNS {
+        /// Creates a new LoggerNS with initalized data
+        /// Automatically sets all values to the following:
+        ///  - log - A null-terminated array
+        ///  - log_id - An array of 0
+        ///  - log_type - An array of LoggingTypeNS::Marker
+        ///  - free_slots - An array of 1024 true's (Tells search that the entire array is empty)
+        ///  - strict_nul - false
+        pub fn new_logger_ns() -> Self {
+            Self {
+                log: [[0; 1024]; 1024],
+                log_id: [0; 1024],
+                log_type: [LoggingTypeNS::Marker; 1024],
+                // To replace with u16 counter, set the counter value to 0
+                free_slots: [true; 1024],
+                strict_nul: false,
+            }
+        }
+
+        /// Sets whether parse_logger and encode_into reject messages where non-NUL data follows a NUL.
+        /// Such data would be silently cut off when the message is read as a C string,
+        /// which usually means the buffer was built wrong.
+        /// When on, both return Err(OverflowError::InvalidIndex(slot)) for the first such slot.
+        /// Off by default, which keeps the lenient behavior.
+        pub fn set_strict_nul(&mut self, strict_nul: bool) {
+            self.strict_nul = strict_nul;
+        }
+
+        /// Goes through the arrays, and gets the first empty slot.
+        // Potential: Remove this Function (if the way slot accessing changes allows this)
+        fn get_next_avaliable_slot(&mut self) -> Option<usize> {
+            for i in 0..MAX_LOG_ENTRIES {
+                if self.free_slots[i] {
+                    // If the function is not removed, this would turn into: ` self.free_slot += 1 '
+                    self.free_slots[i] = false;
+                    return Some(i);
+                }
+            }
+            None
+        }
+
+        /// Iterates over every used slot without copying its buffer.
+        /// Yields the slot, a reference to its message, its ID and its type.
+        pub fn iter_refs(
+            &self,
+        ) -> impl Iterator<Item = (usize, &[c_char; MAX_LOG_LENGTH], usize, LoggingTypeNS)>
+        {
+            (0..MAX_LOG_ENTRIES)
+                .filter(move |&slot| !self.free_slots[slot])
+                .map(move |slot| {
+                    (
+                        slot,
+                        &self.log[slot],
+                        self.log_id[slot],
+                        self.log_type[slot],
+                    )
+                })
+        }
+
+        /// Encodes every used slot into out, without allocating, as back to back records laid out as:
+        ///  - 1 byte - The type: 0 for Error, 1 for Warning, 2 for Log, 3 for Marker.
+        ///  - 8 bytes - The ID as a little-endian u64.
+        ///  - 2 bytes - The message length as a little-endian u16.
+        ///  - length bytes - The message, up to its first NUL, which is not included.
+        ///
+        /// Returns the amount of bytes written,
+        /// or Err(OverflowError::BufferEntryOverflow) if the records do not fit in out.
+        /// With set_strict_nul on, returns Err(OverflowError::InvalidIndex(slot)) for the first
+        /// slot holding data after a NUL, instead of cutting the message at that NUL.
+        /// On error, out may hold the records that were encoded before it.
+        pub fn encode_into(&self, out: &mut [u8]) -> Result<usize, OverflowError> {
+            let mut written = 0;
+            for (slot, message, id, log_type) in self.iter_refs() {
+                if self.strict_nul && has_interior_nul(message) {
+                    return Err(OverflowError::InvalidIndex(slot));
+                }
+                let len = message
+                    .iter()
+                    .position(|&c| c == 0)
+                    .unwrap_or(MAX_LOG_LENGTH);
+                let end = written + 11 + len;
+                if end > out.len() {
+                    return Err(OverflowError::BufferEntryOverflow);
+                }
+
+                out[written] = match log_type {
+                    LoggingTypeNS::Error => 0,
+                    LoggingTypeNS::Warning => 1,
+                    LoggingTypeNS::Log => 2,
+                    LoggingTypeNS::Marker => 3,
+                };
+                out[written + 1..written + 9].copy_from_slice(&(id as u64).to_le_bytes());
+                out[written + 9..written + 11].copy_from_slice(&(len as u16).to_le_bytes());
+                for (byte, &c) in out[written + 11..end].iter_mut().zip(message.iter()) {
+                    *byte = c as u8;
+                }
+                written = end;
+            }
+            Ok(written)
+        }
+
+        /// Adds a marker. 
+        /// Inputs : Option<[c_char; 1024], Option<usize>>
+        pub fn add_marker(
+            &mut self,
+            mut message: Option<[c_char; MAX_LOG_ENTRIES]>,
+            mut id: Option<usize>,
+        ) -> Result<usize, OverflowError> {
+            if message == None {
+                let message_default = "Marker Place";
+                let mut arr: [c_char; MAX_LOG_ENTRIES] = [0; 1024];
+                for i in 0..message_default.len() {
+                    arr[i] = message_default.as_bytes()[i] as c_char;
+                }
+
+                message = Some(arr);
+            }
+            
+
+            if id == None {
+                id = Some(0);
+            }
+            if let Some(slot) = self.get_next_avaliable_slot() {
+                self.log[slot] = Option::expect(message, "ID is None, failed to change value");
+                self.log_id[slot] = Option::expect(id, "ID is None, failed to change value");
+                self.log_type[slot] = LoggingTypeNS::Marker;
+                Ok(slot)
+            } else {
+                Err(OverflowError::BufferEntryOverflow)
+            }
+        }
+
+        /// Adds a Log to your Logger.
+        /// Inputs: [c_char; 1024], usize
+        pub fn add_log(
+            &mut self,
+            message: [c_char; MAX_LOG_ENTRIES],
+            id: usize,
+        ) -> Result<usize, OverflowError> {
+            if let Some(slot) = self.get_next_avaliable_slot() {
+                self.log[slot] = message;
+                self.log_id[slot] = id;
+                self.log_type[slot] = LoggingTypeNS::Log;
+                Ok(slot)
+            } else {
+                Err(OverflowError::BufferEntryOverflow)
+            }
+        }
+
+        /// Adds a Warning to your Logger.
+        /// Inputs: [c_char; 1024], usize
+        pub fn add_warning(
+            &mut self,
+            message: [c_char; 1024],
+            id: usize,
+        ) -> Result<usize, OverflowError> {
+            if let Some(slot) = self.get_next_avaliable_slot() {
+                self.log[slot] = message;
+                self.log_id[slot] = id;
+                self.log_type[slot] = LoggingTypeNS::Warning;
+                Ok(slot)
+            } else {
+                Err(OverflowError::BufferEntryOverflow)
+            }
+        }
+
+        /// Adds an Error to your Logger.
+        /// WARNING! These Errors are UNRECOVERABLE.
+        /// Currently Recoverable Errors are not supported.
+        /// Once the parser is done parsing through everything, if it find an Error, it will panic!
+        /// Inputs: [c_char; 1024], usize
+        pub fn add_error(
+            &mut self,
+            message: [c_char; 1024],
+            id: usize,
+        ) -> Result<usize, OverflowError> {
+            if let Some(slot) = self.get_next_avaliable_slot() {
+                self.log[slot] = message;
+                self.log_id[slot] = id;
+                self.log_type[slot] = LoggingTypeNS::Error;
+                Ok(slot)
+            } else {
+                Err(OverflowError::BufferEntryOverflow)
+            }
+        }   
+
+        /// Parses the LoggerNS.
+        /// Inputs: 
+        ///  Fn([c_char; 1024], usize, LoggingTypeNS) -> Result<(), OverflowError>
+        ///  Fn([c_char; 1024], usize, LoggingTypeNS) -> ()
+        pub fn parse_logger<
+            S: Fn([c_char; 1024], usize, LoggingTypeNS) -> Result<(), OverflowError>,
+            P: Fn([c_char; 1024], usize, LoggingTypeNS) -> (),
+        > (&self, printer: S, crasher: P) -> Result<(), OverflowError> {
+            let mut last_error: Option<usize> = None;
+
+            for i in 0..self.log.len() {
+                if !self.free_slots[i] {
+                    if self.strict_nul && has_interior_nul(&self.log[i]) {
+                        return Err(OverflowError::InvalidIndex(i));
+                    }
+                    match self.log_type[i] {
+                        LoggingTypeNS::Marker | LoggingTypeNS::Log | LoggingTypeNS::Warning => {
+                            if let Err(_) = printer(self.log[i], self.log_id[i], self.log_type[i]) {
+                                return Err(OverflowError::BufferEntryOverflow);
+                            }
+                        }
+                        LoggingTypeNS::Error => {
+                            crasher(self.log[i], self.log_id[i], self.log_type[i]);
+                            last_error = Some(self.log_id[i]);
+                        }
+                    }
+                }    
+            }
+
+            if let Some(error_index) = last_error {
+                return Err(OverflowError::InvalidIndex(error_index));
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Returns whether message holds non-NUL data after its first NUL.
+    fn has_interior_nul(message: &[c_char; MAX_LOG_LENGTH]) -> bool {
+        match message.iter().position(|&c| c == 0) {
+            Some(nul) => message[nul..].iter().any(|&c| c != 0),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod mini_log_tests {
+    use super::no_std_logger;
+    use super::std_logger;
+
+    #[test]
+    fn marking_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        logger.add_marker(None, None);
+        logger.add_marker(None, None);
+
+        logger.parse_logger();
+    }
+
+    #[test]
+    fn logging_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        logger.parse_logger();
+    }
+
+    #[test]
+    fn warning_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        logger.parse_logger();
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+
+        logger.parse_logger();
+    }
+
+    #[test]
+    fn template_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_template("{level} [{id}] {msg} {unknown} {");
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        assert_eq!(
+            logger.render(),
+            "MARKER [0] Logging Enabled {unknown} {\nLOG [1] Testing Log {unknown} {\n"
+        );
+
+        logger.set_template("{level}:");
+        assert!(logger.render().ends_with("LOG: Testing Log\n"));
+    }
+
+    #[test]
+    fn log_results_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        let results = ["1", "x", "2"].iter().map(|s| s.parse::<u8>());
+
+        let ok_count =
+            logger.log_results(results, std_logger::TEST_LOG_ID, std_logger::TEST_ERROR_ID);
+        assert_eq!(ok_count, 2);
+
+        let entries: Vec<_> = logger.entries().collect();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[1].log_type, std_logger::LoggingType::Error);
+        assert_eq!(entries[1].log, "invalid digit found in string");
+        assert_eq!(entries[1].log_id, std_logger::TEST_ERROR_ID);
+        assert_eq!(entries[2].log_type, std_logger::LoggingType::Log);
+        assert_eq!(entries[2].log, "Results Succeeded: 2");
+        assert_eq!(entries[2].log_id, std_logger::TEST_LOG_ID);
+    }
+
+    #[test]
+    fn spill_test() {
+        let path = std::env::temp_dir().join("mini_log_spill_test.log");
+        let _ = std::fs::remove_file(&path);
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_spill(&path, 2);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        assert_eq!(logger.render().lines().count(), 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+
+        logger.flush_spill().unwrap();
+        assert!(logger.render().is_empty());
+
+        let spilled = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(spilled.lines().count(), 4);
+        assert!(spilled.starts_with("[MARKER]: Logging Enabled\n"));
+        assert!(logger.spill_error().is_none());
+
+        std::fs::remove_file(&path).unwrap();
+
+        let mut failing = std_logger::Logger::new_logger();
+        failing.set_spill("/mini_log/does/not/exist/spill.log", 1);
+        failing.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        failing.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        assert_eq!(failing.entries().count(), 3);
+        assert_eq!(
+            failing.spill_error().map(|err| err.kind()),
+            Some(std::io::ErrorKind::NotFound)
+        );
+        assert!(failing.flush_spill().is_err());
+    }
+
+    #[test]
+    fn between_markers_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        logger.add_marker(Some("Start"), None);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_marker(Some("End"), None);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        let phase = logger.between_markers("Start", "End");
+        assert_eq!(phase.len(), 2);
+        assert_eq!(phase[0].log, std_logger::TEST_LOG);
+        assert_eq!(phase[1].log_type, std_logger::LoggingType::Warning);
+
+        assert!(logger.between_markers("End", "Start").is_empty());
+        assert!(logger.between_markers("Missing", "End").is_empty());
+    }
+
+    #[test]
+    fn autoflush_test() {
+        let path = std::env::temp_dir().join("mini_log_autoflush_test.log");
+        let _ = std::fs::remove_file(&path);
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_sink(&path);
+        logger.set_autoflush_every(2);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert!(!path.exists());
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+
+        logger.set_clear_on_flush(true);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 5);
+        assert!(logger.render().is_empty());
+        assert!(logger.autoflush_error().is_none());
+
+        std::fs::remove_file(&path).unwrap();
+
+        let mut failing = std_logger::Logger::new_logger();
+        failing.set_sink("/mini_log/does/not/exist/sink.log");
+        failing.set_autoflush_every(1);
+        failing.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert_eq!(
+            failing.autoflush_error().map(|err| err.kind()),
+            Some(std::io::ErrorKind::NotFound)
+        );
+    }
+
+    #[test]
+    fn aggregate_test() {
+        let mut first = std_logger::Logger::new_logger();
+        first.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        let mut second = std_logger::Logger::new_logger();
+        second.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let aggregated = std_logger::Logger::aggregate(&[first.clone(), second, first]);
+        let entries: Vec<_> = aggregated.entries().collect();
+
+        assert_eq!(entries.len(), 4);
+        assert!(entries
+            .windows(2)
+            .all(|pair| pair[0].log_time <= pair[1].log_time));
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|entry| entry.log == std_logger::TEST_LOG)
+                .count(),
+            1
+        );
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn otel_test() {
+        use opentelemetry::logs::Severity;
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let records = logger.to_otel_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].severity_number, Some(Severity::Trace));
+        assert_eq!(records[1].severity_number, Some(Severity::Warn));
+        assert_eq!(records[1].attributes.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn truncate_at_first_error_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.truncate_at_first_error();
+        assert_eq!(logger.entries().count(), 2);
+
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.truncate_at_first_error();
+
+        let last = logger.entries().last().unwrap();
+        assert_eq!(logger.entries().count(), 3);
+        assert_eq!(last.log_type, std_logger::LoggingType::Error);
+    }
+
+    #[test]
+    fn by_id_map_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_LOG_ID);
+
+        let map = logger.by_id_map();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&std_logger::TEST_LOG_ID].log, std_logger::TEST_WARN);
+        assert_eq!(map[&std_logger::INIT_MARK_ID].index, 0);
+    }
+
+    /// LoggerNS is too large for the default test thread stack.
+    fn with_large_stack<F: FnOnce() + Send + 'static>(test: F) {
+        std::thread::Builder::new()
+            .stack_size(16 * 1024 * 1024)
+            .spawn(test)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn iter_refs_test() {
+        with_large_stack(|| {
+            let mut logger = no_std_logger::LoggerNS::new_logger_ns();
+            logger.add_marker(None, Some(7)).unwrap();
+            logger
+                .add_log([0; no_std_logger::MAX_LOG_LENGTH], 8)
+                .unwrap();
+
+            let ids: Vec<usize> = logger.iter_refs().map(|(_, _, id, _)| id).collect();
+            assert_eq!(ids, vec![7, 8]);
+
+            let (slot, message, _, _) = logger.iter_refs().next().unwrap();
+            assert_eq!(slot, 0);
+            assert_eq!(message[0], b'M' as core::ffi::c_char);
+        });
+    }
+
+    #[test]
+    fn encode_into_test() {
+        with_large_stack(|| {
+            let mut logger = no_std_logger::LoggerNS::new_logger_ns();
+            logger.add_marker(None, Some(7)).unwrap();
+            let mut message = [0; no_std_logger::MAX_LOG_LENGTH];
+            message[0] = b'h' as core::ffi::c_char;
+            message[1] = b'i' as core::ffi::c_char;
+            logger.add_warning(message, 8).unwrap();
+
+            let mut out = [0u8; 64];
+            let written = logger.encode_into(&mut out).unwrap();
+            assert_eq!(written, 11 + "Marker Place".len() + 11 + 2);
+            assert_eq!(out[0], 3);
+            assert_eq!(&out[1..9], &7u64.to_le_bytes());
+            assert_eq!(&out[9..11], &12u16.to_le_bytes());
+            assert_eq!(&out[11..23], b"Marker Place");
+            assert_eq!(
+                &out[23..written],
+                &[1, 8, 0, 0, 0, 0, 0, 0, 0, 2, 0, b'h', b'i']
+            );
+
+            let mut small = [0u8; 30];
+            assert!(matches!(
+                logger.encode_into(&mut small),
+                Err(no_std_logger::OverflowError::BufferEntryOverflow)
+            ));
+
+            message[3] = b'!' as core::ffi::c_char;
+            logger.add_log(message, 9).unwrap();
+            let lenient = logger.encode_into(&mut out).unwrap();
+            assert_eq!(&out[lenient - 2..lenient], b"hi");
+
+            logger.set_strict_nul(true);
+            assert!(matches!(
+                logger.encode_into(&mut out),
+                Err(no_std_logger::OverflowError::InvalidIndex(2))
+            ));
+        });
+    }
+
+    #[test]
+    fn os_error_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+
+        let _ = std::fs::File::open("/mini_log/does/not/exist");
+        let expected = std::io::Error::last_os_error().raw_os_error().unwrap();
+        logger.add_os_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+
+        let rendered = logger.render();
+        let last_line = rendered.lines().last().unwrap();
+        assert!(last_line.starts_with(&format!(
+            "[ERROR]: Error: Testing Error; Error ID: 3 (os error {}: ",
+            expected
+        )));
+        assert!(!last_line.ends_with(&format!("(os error {}))", expected)));
+
+        let (code, _) = logger.entries().last().unwrap().log_os_error.unwrap();
+        assert_eq!(code, expected);
+    }
+
+    #[test]
+    fn chunks_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        for _ in 0..4 {
+            logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        }
+
+        let chunks = logger.chunks(2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].entries().count(), 2);
+        assert_eq!(chunks[2].entries().count(), 1);
+        assert_eq!(
+            chunks[1].entries().next().unwrap().log,
+            std_logger::TEST_LOG
+        );
+    }
+
+    #[test]
+    fn parse_keeps_equality_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let copy = logger.clone();
+        assert_eq!(copy, logger);
+        logger.parse_logger();
+        assert_eq!(copy, logger);
+        logger.to_json_incremental();
+        assert_eq!(copy, logger);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert_ne!(copy, logger);
+    }
+
+    #[test]
+    fn into_entries_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let (tx, rx) = mpsc::channel();
+        tx.send(logger.into_entries()).unwrap();
+
+        let entries = rx.recv().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].log, std_logger::TEST_WARN);
+        assert_eq!(entries[1].log_id, std_logger::TEST_WARN_ID);
+        assert_eq!(entries[1].log_type, std_logger::LoggingType::Warning);
+    }
+
+    #[test]
+    fn child_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        let mut child = logger.child("load");
+        child.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.absorb_child("load", child);
+
+        let mut other = std_logger::Logger::default();
+        other.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.absorb_child("save", other);
+
+        let logs: Vec<String> = logger
+            .entries()
+            .map(|entry| entry.log.to_string())
+            .collect();
+        assert_eq!(
+            logs,
+            vec![
+                std_logger::INIT_MARK,
+                "begin scope: load",
+                std_logger::TEST_LOG,
+                "end scope: load",
+                "begin scope: save",
+                std_logger::TEST_WARN,
+                "end scope: save",
+            ]
+        );
+    }
+
+    #[test]
+    fn entries_per_second_test() {
+        let mut logger = std_logger::Logger::default();
+        assert_eq!(logger.entries_per_second(), 0.0);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert_eq!(logger.entries_per_second(), 0.0);
+
+        thread::sleep(std::time::Duration::from_millis(20));
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        let rate = logger.entries_per_second();
+        assert!(rate > 0.0 && rate <= 100.0);
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn encrypted_file_test() {
+        let path = std::env::temp_dir().join("mini_log_encrypted_test.log");
+        let key = [7; 32];
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.write_to_file_encrypted(&path, &key).unwrap();
+
+        let raw = std::fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains(std_logger::TEST_LOG));
+
+        let decrypted = std_logger::Logger::read_from_file_encrypted(&path, &key).unwrap();
+        assert_eq!(decrypted, logger.render());
+        assert!(std_logger::Logger::read_from_file_encrypted(&path, &[8; 32]).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn longest_gap_test() {
+        let mut logger = std_logger::Logger::default();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert_eq!(logger.longest_gap(), None);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        thread::sleep(std::time::Duration::from_millis(20));
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let (index, gap) = logger.longest_gap().unwrap();
+        assert_eq!(index, 2);
+        assert!(gap >= std::time::Duration::from_millis(20));
+    }
+
+    #[test]
+    fn folded_stacks_test() {
+        let mut logger = std_logger::Logger::new_logger();
+
+        let mut outer = logger.child("outer");
+        let mut inner = outer.child("inner");
+        thread::sleep(std::time::Duration::from_millis(10));
+        inner.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        outer.absorb_child("inner", inner);
+        logger.absorb_child("outer", outer);
+
+        logger.add_marker(Some("end scope: missing"), None);
+        logger.add_marker(Some("begin scope: unclosed"), None);
+
+        let folded = logger.folded_stacks();
+        let lines: Vec<&str> = folded.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("outer;inner "));
+        assert!(lines[1].starts_with("outer "));
+        assert_eq!(lines[2], "unclosed 0");
+
+        let inner_us: u128 = lines[0].rsplit(' ').next().unwrap().parse().unwrap();
+        assert!(inner_us >= 10_000);
+    }
+
+    #[test]
+    fn fixed_records_test() {
+        let mut logger = std_logger::Logger::default();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_log("Hi", std_logger::TEST_LOG_ID);
+
+        let records = logger.to_fixed_records(4);
+        assert_eq!(records.len(), 2 * 13);
+        assert_eq!(
+            &records[..13],
+            &[1, 2, 0, 0, 0, 0, 0, 0, 0, b'T', b'e', b's', b't']
+        );
+        assert_eq!(
+            &records[13..],
+            &[2, 1, 0, 0, 0, 0, 0, 0, 0, b'H', b'i', 0, 0]
+        );
+    }
+
+    #[test]
+    fn reclassify_by_rules_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_log("disk almost full", std_logger::TEST_LOG_ID);
+        logger.add_log("disk failure", std_logger::TEST_LOG_ID);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        logger.reclassify_by_rules(&[
+            ("failure", std_logger::LoggingType::Error),
+            ("disk", std_logger::LoggingType::Warning),
+        ]);
+
+        let types: Vec<_> = logger.entries().map(|entry| entry.log_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                std_logger::LoggingType::Marker,
+                std_logger::LoggingType::Warning,
+                std_logger::LoggingType::Error,
+                std_logger::LoggingType::Log,
+            ]
+        );
+    }
+
+    #[cfg(feature = "tcp")]
+    #[test]
+    fn stream_to_tcp_test() {
+        use std::io::Read;
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log("Say \"hi\"", std_logger::TEST_LOG_ID);
+
+        let sender = thread::spawn(move || logger.stream_to_tcp(&addr));
+        let mut received = String::new();
+        listener
+            .accept()
+            .unwrap()
+            .0
+            .read_to_string(&mut received)
+            .unwrap();
+        sender.join().unwrap().unwrap();
+
+        let lines: Vec<&str> = received.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with(r#"{"type":"LOG","id":1,"message":"Say \"hi\"","time":"#));
+    }
+
+    #[test]
+    fn collapse_loops_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        for _ in 0..3 {
+            logger.add_log("retrying", std_logger::TEST_LOG_ID);
+            logger.add_warning("request failed", std_logger::TEST_WARN_ID);
+        }
+        logger.add_log("retrying", std_logger::TEST_LOG_ID);
+        logger.add_log("done", std_logger::TEST_LOG_ID);
+        logger.add_log("done", std_logger::TEST_LOG_ID);
+
+        let mut single = logger.clone();
+        single.collapse_loops(2, 8);
+        let logs: Vec<String> = single
+            .entries()
+            .map(|entry| entry.log.to_string())
+            .collect();
+        assert_eq!(
+            logs,
+            vec![
+                std_logger::INIT_MARK,
+                "retrying",
+                "request failed",
+                "[previous 2 lines repeated 2 times]",
+                "retrying",
+                "done",
+                "done",
+            ]
+        );
+
+        let mut capped = logger.clone();
+        capped.collapse_loops(1, 1);
+        assert_eq!(capped.entries().count(), 10);
+        assert_eq!(
+            capped.entries().last().unwrap().log,
+            "[previous 1 lines repeated 1 times]"
+        );
+
+        logger.collapse_loops(1, 8);
+        let logs: Vec<String> = logger
+            .entries()
+            .map(|entry| entry.log.to_string())
+            .collect();
+        assert_eq!(logs.len(), 7);
+        assert_eq!(logs[3], "[previous 2 lines repeated 2 times]");
+        assert_eq!(logs[5], "done");
+        assert_eq!(logs[6], "[previous 1 lines repeated 1 times]");
+    }
+
+    #[test]
+    fn ended_cleanly_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert!(!logger.ended_cleanly());
+
+        logger.add_marker(Some("Finished"), None);
+        assert!(logger.ended_cleanly());
+
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.add_marker(Some("Finished"), None);
+        assert!(!logger.ended_cleanly());
+        logger.set_warn_unparsed_errors(false);
+    }
+
+    #[test]
+    fn render_entry_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        assert_eq!(
+            logger.render_entry(1).unwrap(),
+            "[WARNING]: Warning: Testing Warning; Warning ID: 2"
+        );
+        assert_eq!(logger.render_entry(2), None);
+    }
+
+    #[test]
+    fn distinct_id_count_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        assert_eq!(logger.distinct_id_count(), 0);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        assert_eq!(logger.distinct_id_count(), 2);
+
+        logger.add_marker(None, None);
+        assert_eq!(logger.distinct_id_count(), 3);
+
+        let mut plain = std_logger::Logger::default();
+        plain.add_marker(None, None);
+        assert_eq!(plain.distinct_id_count(), 1);
+
+        let mut reclassified = std_logger::Logger::new_logger();
+        reclassified.reclassify_by_rules(&[("Logging", std_logger::LoggingType::Warning)]);
+        assert_eq!(reclassified.distinct_id_count(), 0);
+
+        let aggregated = std_logger::Logger::aggregate(&[
+            std_logger::Logger::new_logger(),
+            std_logger::Logger::new_logger(),
+        ]);
+        assert_eq!(aggregated.distinct_id_count(), 0);
+        assert!(aggregated.summarize_by_message().is_empty());
+        aggregated.assert_sequence(&[]);
+    }
+
+    #[test]
+    fn strict_nul_test() {
+        with_large_stack(|| {
+            let mut logger = no_std_logger::LoggerNS::new_logger_ns();
+            let mut message = [0; no_std_logger::MAX_LOG_LENGTH];
+            message[0] = b'A' as core::ffi::c_char;
+            message[2] = b'B' as core::ffi::c_char;
+            logger.add_log(message, 1).unwrap();
+
+            let printer = |_, _, _| Ok(());
+            let crasher = |_, _, _| {};
+            assert!(logger.parse_logger(printer, crasher).is_ok());
+
+            logger.set_strict_nul(true);
+            assert!(matches!(
+                logger.parse_logger(printer, crasher),
+                Err(no_std_logger::OverflowError::InvalidIndex(0))
+            ));
+        });
+    }
+
+    #[test]
+    fn canonical_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_template("{time} {level}");
+        logger.add_log("Trailing   ", std_logger::TEST_LOG_ID);
+
+        assert_eq!(
+            logger.canonical(),
+            "<TIME> MARKER Logging Enabled\n<TIME> LOG Trailing\n"
+        );
+        assert!(!logger.render().contains("<TIME>"));
+    }
+
+    #[test]
+    fn separator_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_separator();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        assert_eq!(
+            logger.render().lines().nth(2),
+            Some(std_logger::SEPARATOR_MARK)
+        );
+        assert_eq!(logger.distinct_id_count(), 1);
+        logger.assert_sequence(&[
+            (std_logger::LoggingType::Log, std_logger::TEST_LOG),
+            (std_logger::LoggingType::Log, std_logger::TEST_LOG),
+        ]);
+
+        logger.add_separator();
+        assert!(!logger.ended_cleanly());
+
+        logger.reclassify_by_rules(&[("-", std_logger::LoggingType::Warning)]);
+        let last = logger.entries().last().unwrap();
+        assert_eq!(last.log_type, std_logger::LoggingType::Marker);
+
+        logger.add_marker(Some(std_logger::SEPARATOR_MARK), None);
+        assert!(logger.ended_cleanly());
+        assert_eq!(logger.render().lines().last(), Some("[MARKER]: ---"));
+        assert_eq!(logger.distinct_id_count(), 2);
+    }
+
+    #[test]
+    fn find_oversized_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log("short", std_logger::TEST_LOG_ID);
+        logger.add_log("much too long", std_logger::TEST_LOG_ID);
+        logger.add_log("exactly 10", std_logger::TEST_LOG_ID);
+
+        assert_eq!(logger.find_oversized(10), vec![0, 2]);
+    }
+
+    #[cfg(feature = "columnar")]
+    #[test]
+    fn arrow_test() {
+        use arrow::array::{Array, StringArray, UInt64Array};
+        use arrow::datatypes::DataType;
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let batch = logger.to_arrow();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::UInt64);
+        assert!(matches!(
+            batch.schema().field(2).data_type(),
+            DataType::Dictionary(_, _)
+        ));
+        assert!(batch
+            .columns()
+            .iter()
+            .all(|column| column.null_count() == 0));
+
+        let messages = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let ids = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        assert_eq!(messages.value(1), std_logger::TEST_WARN);
+        assert_eq!(ids.value(1), std_logger::TEST_WARN_ID as u64);
+    }
+
+    #[test]
+    fn assert_sequence_test() {
+        use std_logger::LoggingType;
+
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
 
-        /// Adds an Error to your Logger.
-        /// WARNING! These Errors are UNRECOVERABLE.
-        /// Currently Recoverable Errors are not supported.
-        /// Once the parser is done parsing through everything, if it find an Error, it will panic!
-        /// Inputs: [c_char; 1024], usize
-        pub fn add_error(
-            &mut self,
-            message: [c_char; 1024],
-            id: usize,
-        ) -> Result<usize, OverflowError> {
-            if let Some(slot) = self.get_next_avaliable_slot() {
-                self.log[slot] = message;
-                self.log_id[slot] = id;
-                self.log_type[slot] = LoggingTypeNS::Error;
-                Ok(slot)
-            } else {
-                Err(OverflowError::BufferEntryOverflow)
-            }
-        }   
+        logger.assert_sequence(&[
+            (LoggingType::Log, std_logger::TEST_LOG),
+            (LoggingType::Warning, std_logger::TEST_WARN),
+        ]);
 
-        /// Parses the LoggerNS.
-        /// Inputs: 
-        ///  Fn([c_char; 1024], usize, LoggingTypeNS) -> Result<(), OverflowError>
-        ///  Fn([c_char; 1024], usize, LoggingTypeNS) -> ()
-        pub fn parse_logger<
-            S: Fn([c_char; 1024], usize, LoggingTypeNS) -> Result<(), OverflowError>,
-            P: Fn([c_char; 1024], usize, LoggingTypeNS) -> (),
-        > (&self, printer: S, crasher: P) -> Result<(), OverflowError> {
-            let mut last_error: Option<usize> = None;
+        let mismatch = std::panic::catch_unwind(|| {
+            logger.assert_sequence(&[(LoggingType::Log, std_logger::TEST_LOG)]);
+        });
+        let message = mismatch.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("log sequence mismatch at position 1"));
+        assert!(message.contains("> 1: <end>"));
+        assert!(message.contains("> 1: [WARNING] Testing Warning"));
+    }
 
-            for i in 0..self.log.len() {
-                if !self.free_slots[i] {
-                    match self.log_type[i] {
-                        LoggingTypeNS::Marker | LoggingTypeNS::Log | LoggingTypeNS::Warning => {
-                            if let Err(_) = printer(self.log[i], self.log_id[i], self.log_type[i]) {
-                                return Err(OverflowError::BufferEntryOverflow);
-                            }
-                        }
-                        LoggingTypeNS::Error => {
-                            crasher(self.log[i], self.log_id[i], self.log_type[i]);
-                            last_error = Some(self.log_id[i]);
-                        }
-                    }
-                }    
-            }
+    #[test]
+    fn json_incremental_test() {
+        let mut logger = std_logger::Logger::default();
+        logger.add_log("Say \"hi\"", std_logger::TEST_LOG_ID);
 
-            if let Some(error_index) = last_error {
-                return Err(OverflowError::InvalidIndex(error_index));
-            }
+        let first = logger.to_json_incremental();
+        assert!(first.starts_with(r#"[{"type":"LOG","id":1,"message":"Say \"hi\"","time":"#));
+        assert!(first.ends_with("}]"));
+        assert_eq!(logger.to_json_incremental(), "[]");
 
-            Ok(())
-        }
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        let second = logger.to_json_incremental();
+        assert!(second.starts_with(r#"[{"type":"WARNING","id":2,"#));
+        assert_eq!(second.matches("\"type\"").count(), 1);
     }
-}
 
-#[cfg(test)]
-mod mini_log_tests {
-    use super::std_logger;
+    #[test]
+    fn id_names_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        let mut names = std::collections::HashMap::new();
+        names.insert(std_logger::TEST_LOG_ID, "startup".to_string());
+        logger.set_id_names(names);
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let rendered = logger.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[1], "[LOG]: Info: Testing Log; Info ID: 1 (startup)");
+        assert_eq!(
+            lines[2],
+            "[WARNING]: Warning: Testing Warning; Warning ID: 2"
+        );
+    }
 
     #[test]
-    fn marking_test() {
+    fn synchronized_parse_test() {
+        let logger = Arc::new(std_logger::Logger::new_logger());
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let logger_cloned = Arc::clone(&logger);
+                thread::spawn(move || logger_cloned.parse_logger_synchronized())
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[cfg(feature = "rusqlite")]
+    #[test]
+    fn sqlite_test() {
+        let path = std::env::temp_dir().join("mini_log_sqlite_test.db");
+        let _ = std::fs::remove_file(&path);
+
         let mut logger = std_logger::Logger::new_logger();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.write_to_sqlite(&path).unwrap();
 
-        logger.add_marker(None, None);
-        logger.add_marker(None, None);
+        let connection = rusqlite::Connection::open(&path).unwrap();
+        let (log_type, log_id, message): (String, i64, String) = connection
+            .query_row(
+                "SELECT type, log_id, message FROM logs WHERE id_seq = 2",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(log_type, "WARNING");
+        assert_eq!(log_id, std_logger::TEST_WARN_ID as i64);
+        assert_eq!(message, std_logger::TEST_WARN);
 
-        logger.parse_logger();
+        drop(connection);
+        std::fs::remove_file(&path).unwrap();
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn logging_test() {
+    fn json_maps_test() {
         let mut logger = std_logger::Logger::new_logger();
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let mut maps = logger.to_json_maps();
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps[1]["type"], "WARNING");
+        assert_eq!(maps[1]["id"], std_logger::TEST_WARN_ID);
+        assert_eq!(maps[1]["message"], std_logger::TEST_WARN);
+        assert!(maps[1]["time"].is_f64());
 
+        maps[1].insert("host".to_string(), "web-1".into());
+        let json = serde_json::Value::Object(maps.remove(1)).to_string();
+        assert!(json.contains("\"host\":\"web-1\""));
+    }
+
+    #[test]
+    fn runs_test() {
+        let mut logger = std_logger::Logger::new_logger();
         logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
         logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
 
-        logger.parse_logger();
+        let runs: Vec<_> = logger
+            .runs()
+            .into_iter()
+            .map(|(log_type, run)| (log_type, run.len()))
+            .collect();
+        assert_eq!(
+            runs,
+            vec![
+                (std_logger::LoggingType::Marker, 1),
+                (std_logger::LoggingType::Log, 2),
+                (std_logger::LoggingType::Warning, 1),
+                (std_logger::LoggingType::Log, 1),
+            ]
+        );
     }
 
     #[test]
-    fn warning_test() {
+    fn rebase_ids_test() {
+        let mut first = std_logger::Logger::new_logger();
+        first.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        let mut second = std_logger::Logger::new_logger();
+        second.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+
+        let next = first.rebase_ids(100);
+        assert_eq!(second.rebase_ids(next), 104);
+
+        let ids: Vec<usize> = first
+            .entries()
+            .chain(second.entries())
+            .map(|e| e.log_id)
+            .collect();
+        assert_eq!(ids, vec![100, 101, 102, 103]);
+
+        assert_eq!(first.rebase_ids(usize::MAX - 1), usize::MAX);
+        let ids: Vec<usize> = first.entries().map(|e| e.log_id).collect();
+        assert_eq!(ids, vec![usize::MAX - 1, usize::MAX]);
+        assert_eq!(second.rebase_ids(usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn entries_after_first_error_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert!(logger.entries_after_first_error().is_empty());
+
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.set_warn_unparsed_errors(false);
+
+        let after = logger.entries_after_first_error();
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].index, 3);
+        assert_eq!(after[0].log, std_logger::TEST_WARN);
+    }
+
+    #[test]
+    fn context_id_test() {
         let mut logger = std_logger::Logger::new_logger();
+        logger.add_log("boot", 1);
+        logger.set_context_id("req-1");
+        logger.add_log("handling", 2);
+        logger.set_context_id("req-2");
+        logger.add_warning("slow", 3);
+        logger.clear_context_id();
+        logger.add_log("idle", 4);
+
+        let contexts: Vec<Option<&str>> = logger.entries().map(|e| e.log_context).collect();
+        assert_eq!(
+            contexts,
+            vec![None, None, Some("req-1"), Some("req-2"), None]
+        );
+        assert_eq!(
+            logger.render_entry(2).unwrap(),
+            "[LOG]: Info: handling; Info ID: 2; Context: req-1"
+        );
 
+        logger.set_template("[{ctx}] {level}");
+        assert_eq!(logger.render_entry(3).unwrap(), "[req-2] WARNING slow");
+        assert_eq!(logger.render_entry(4).unwrap(), "[] LOG idle");
+    }
+
+    #[test]
+    fn sparkline_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
         logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_separator();
+        logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+
+        assert_eq!(logger.to_sparkline(), "|..WE");
+    }
+
+    #[test]
+    fn aos_round_trip_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.set_context_id("req-1");
         logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        logger.add_os_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
 
-        logger.parse_logger();
+        let entries = logger.to_aos();
+        assert_eq!(entries.len(), 4);
+
+        let mut rebuilt = std_logger::Logger::from_aos(entries);
+        rebuilt.set_warn_unparsed_errors(false);
+        assert_eq!(rebuilt.to_aos(), logger.to_aos());
+        assert_eq!(rebuilt.render(), logger.render());
     }
 
     #[test]
-    #[should_panic]
-    fn error_test() {
+    fn escape_control_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log("\x1b[31mred\r", 1);
+        assert_eq!(
+            logger.render_entry(1).unwrap(),
+            "[LOG]: Info: \x1b[31mred\r; Info ID: 1"
+        );
+
+        logger.set_escape_control(true);
+        assert_eq!(
+            logger.render_entry(1).unwrap(),
+            "[LOG]: Info: \\x1b[31mred\\x0d; Info ID: 1"
+        );
+        assert_eq!(logger.entries().nth(1).unwrap().log, "\x1b[31mred\r");
+    }
+
+    #[test]
+    fn in_time_range_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log("before", 1);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let start = std::time::SystemTime::now();
+        logger.add_log("during", 2);
+        let end = std::time::SystemTime::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        logger.add_log("after", 3);
+
+        let window: Vec<&str> = logger
+            .in_time_range(start, end)
+            .iter()
+            .map(|entry| entry.log)
+            .collect();
+        assert_eq!(window, vec!["during"]);
+
+        let times: Vec<_> = logger.entries().map(|entry| entry.log_time).collect();
+        assert_eq!(logger.in_time_range(times[2], times[2]).len(), 1);
+        assert!(logger.in_time_range(times[3], times[1]).is_empty());
+    }
+
+    #[test]
+    fn warning_before_error_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_warning("disk almost full", 1);
+        logger.add_warning("disk full", 2);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert!(logger.warning_before_error().is_none());
+
+        logger.add_error("write failed", 3);
+        logger.add_warning("retrying", 4);
+        logger.add_error("write failed again", 5);
+
+        let (warning, error) = logger.warning_before_error().unwrap();
+        assert_eq!((warning.index, warning.log), (2, "disk full"));
+        assert_eq!((error.index, error.log), (4, "write failed"));
+
+        let mut no_warning = std_logger::Logger::new_logger();
+        no_warning.set_warn_unparsed_errors(false);
+        no_warning.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        assert!(no_warning.warning_before_error().is_none());
+    }
+
+    #[test]
+    fn byte_budget_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(&"a".repeat(100), 1);
+        logger.add_marker(Some("checkpoint"), Some(2));
+        logger.add_log("bb", 3);
+        logger.add_warning("ccc", 4);
+        let markers = std_logger::INIT_MARK.len() + "checkpoint".len();
+        assert_eq!(logger.byte_size(), markers + 105);
+
+        logger.enforce_byte_budget(markers + 5);
+        let kept: Vec<&str> = logger.entries().map(|entry| entry.log).collect();
+        assert_eq!(kept, vec![std_logger::INIT_MARK, "checkpoint", "bb", "ccc"]);
+
+        logger.enforce_byte_budget(0);
+        assert_eq!(logger.byte_size(), markers);
+        assert_eq!(logger.entries().count(), 2);
+    }
+
+    #[test]
+    fn large_byte_budget_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        for i in 0..100_000 {
+            logger.add_log(&format!("{:05}", i), std_logger::TEST_LOG_ID);
+        }
+        logger.to_json_incremental();
+
+        logger.enforce_byte_budget(std_logger::INIT_MARK.len() + 50);
+        let kept: Vec<&str> = logger.entries().skip(1).map(|entry| entry.log).collect();
+        assert_eq!(kept.len(), 10);
+        assert_eq!(kept[0], "99990");
+        assert_eq!(logger.to_json_incremental(), "[]");
+    }
+
+    #[test]
+    fn compare_runs_test() {
+        let mut before = std_logger::Logger::new_logger();
+        before.set_warn_unparsed_errors(false);
+        before.add_log("connecting", 1);
+        before.add_error("connection refused", 2);
+        before.add_log("connecting", 1);
+        before.add_separator();
+
+        let mut after = std_logger::Logger::new_logger();
+        after.add_log("connecting", 1);
+        after.add_warning("slow handshake", 3);
+
+        assert_eq!(
+            std_logger::Logger::compare_runs(&before, &after),
+            "only in before:\n  [ERROR]: connection refused (x1)\n\
+             only in after:\n  [WARNING]: slow handshake (x1)\n\
+             in both:\n  [LOG]: connecting (2 -> 1, -1)\n"
+        );
+    }
+
+    #[test]
+    fn sequence_test() {
+        let mut first = std_logger::Logger::new_logger();
+        let mut second = std_logger::Logger::new_logger();
+        first.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        second.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        first.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+
+        let sequences: Vec<u64> = first.entries().map(|entry| entry.log_sequence).collect();
+        let second_log = second.entries().nth(1).unwrap().log_sequence;
+        assert!(sequences[0] < sequences[1]);
+        assert!(sequences[1] < second_log && second_log < sequences[2]);
+
+        assert_eq!(
+            first.render_entry(1).unwrap(),
+            "[LOG]: Info: Testing Log; Info ID: 1"
+        );
+        first.set_show_sequence(true);
+        assert_eq!(
+            first.render_entry(1).unwrap(),
+            format!("#{} [LOG]: Info: Testing Log; Info ID: 1", sequences[1])
+        );
+        assert!(first.canonical().contains("#<SEQ> [WARNING]"));
+    }
+
+    #[test]
+    fn clf_test() {
+        let entry = std_logger::OwnedLogEntry {
+            log: "GET /index.html \"home\"".to_string(),
+            log_id: 7,
+            log_type: std_logger::LoggingType::Error,
+            log_time: std::time::UNIX_EPOCH + std::time::Duration::from_secs(971_186_136),
+            log_os_error: None,
+            log_context: None,
+            log_sequence: 0,
+            log_separator: false,
+            log_init: false,
+        };
+        let mut logger = std_logger::Logger::from_aos(vec![entry]);
+        logger.set_warn_unparsed_errors(false);
+
+        assert_eq!(
+            logger.to_clf(|entry| format!("10.0.0.{}", entry.log_id)),
+            "10.0.0.7 - - [10/Oct/2000:13:55:36 +0000] \"GET /index.html \\\"home\\\"\" 500 22\n"
+        );
+
+        let leap_day = std_logger::Logger::from_aos(vec![std_logger::OwnedLogEntry {
+            log: std_logger::INIT_MARK.to_string(),
+            log_id: std_logger::INIT_MARK_ID,
+            log_type: std_logger::LoggingType::Marker,
+            log_time: std::time::UNIX_EPOCH + std::time::Duration::from_secs(951_782_400),
+            log_os_error: None,
+            log_context: None,
+            log_sequence: 0,
+            log_separator: false,
+            log_init: false,
+        }]);
+        assert_eq!(
+            leap_day.to_clf(|_| "-".to_string()),
+            "- - - [29/Feb/2000:00:00:00 +0000] \"Logging Enabled\" 200 15\n"
+        );
+    }
+
+    #[test]
+    fn logged_anything_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        assert!(!logger.logged_anything());
+
+        logger.rebase_ids(100);
+        assert!(!logger.logged_anything());
+
+        let aggregated = std_logger::Logger::aggregate(&[logger.clone(), logger.clone()]);
+        assert!(!aggregated.logged_anything());
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert!(logger.logged_anything());
+
+        let mut plain = std_logger::Logger::default();
+        plain.add_marker(None, None);
+        assert!(plain.logged_anything());
+    }
+
+    #[test]
+    fn tree_test() {
         let mut logger = std_logger::Logger::new_logger();
+        logger.add_log("request", 10);
+        logger.add_log("query", 11);
+        logger.add_log("row", 12);
+        logger.add_log("response", 13);
+        logger.add_log("orphan", 21);
+        logger.add_log("looped", 30);
+
+        let tree = logger.to_tree(|id| match id {
+            11 | 13 => Some(10),
+            12 => Some(11),
+            21 => Some(20),
+            30 => Some(31),
+            31 => Some(30),
+            _ => None,
+        });
+
+        assert_eq!(tree.roots.len(), 4);
+        assert_eq!(tree.roots[1].children.len(), 2);
+        assert_eq!(tree.roots[1].children[0].children[0].entry.log, "row");
+        assert_eq!(
+            tree.render(),
+            "[MARKER]: Logging Enabled\n\
+             [LOG]: Info: request; Info ID: 10\n\
+             \x20 [LOG]: Info: query; Info ID: 11\n\
+             \x20   [LOG]: Info: row; Info ID: 12\n\
+             \x20 [LOG]: Info: response; Info ID: 13\n\
+             [LOG]: Info: orphan; Info ID: 21\n\
+             [LOG]: Info: looped; Info ID: 30\n"
+        );
+    }
 
+    #[test]
+    fn ci_summary_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
         logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
+        assert_eq!(
+            logger.ci_summary(),
+            "::mini_log::result errors=1 warnings=2"
+        );
+
+        logger.set_ci_summary_prefix("::notice title=mini_log::");
+        assert_eq!(
+            logger.ci_summary(),
+            "::notice title=mini_log:: errors=1 warnings=2"
+        );
+        logger.print_ci_summary();
+    }
+
+    #[test]
+    fn flush_throttled_test() {
+        struct CountingWriter {
+            written: Vec<u8>,
+            flushes: usize,
+        }
+
+        impl std::io::Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.written.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                self.flushes += 1;
+                Ok(())
+            }
+        }
+
+        let mut logger = std_logger::Logger::new_logger();
+        for _ in 0..4 {
+            logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        }
+
+        let mut out = CountingWriter {
+            written: Vec::new(),
+            flushes: 0,
+        };
+        logger
+            .flush_throttled(&mut out, 2, std::time::Duration::from_millis(1))
+            .unwrap();
+        assert_eq!(out.flushes, 3);
+        assert_eq!(String::from_utf8(out.written).unwrap(), logger.render());
+    }
+
+    #[test]
+    fn join_continuations_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_error("panicked at src/main.rs:3", 9);
+        logger.add_log("  0: main", 9);
+        logger.add_log("  1: start", 9);
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_log("lone", 9);
+
+        logger.join_continuations(9);
+        logger.assert_sequence(&[
+            (
+                std_logger::LoggingType::Error,
+                "panicked at src/main.rs:3\n  0: main\n  1: start",
+            ),
+            (std_logger::LoggingType::Log, std_logger::TEST_LOG),
+            (std_logger::LoggingType::Log, "lone"),
+        ]);
+    }
+
+    #[test]
+    fn counter_test() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let errors = Arc::new(AtomicUsize::new(0));
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.register_counter(std_logger::LoggingType::Error, Arc::clone(&errors));
+        logger.register_counter(std_logger::LoggingType::Warning, Arc::clone(&warnings));
+
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        logger.add_warning(std_logger::TEST_WARN, std_logger::TEST_WARN_ID);
         logger.add_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
+        logger.add_os_error(std_logger::TEST_ERROR, std_logger::TEST_ERROR_ID);
 
-        logger.parse_logger();
+        let reader = {
+            let errors = Arc::clone(&errors);
+            std::thread::spawn(move || errors.load(Ordering::Relaxed))
+        };
+        assert_eq!(reader.join().unwrap(), 2);
+        assert_eq!(warnings.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn repair_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.add_log(std_logger::TEST_LOG, std_logger::TEST_LOG_ID);
+        assert!(logger.invariant_holds());
+        assert_eq!(logger.repair(), 0);
+        assert_eq!(logger.entries().count(), 2);
+    }
+
+    #[test]
+    fn summarize_by_message_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_log("retrying", 1);
+        logger.add_warning("retrying", 1);
+        logger.add_error("timeout", 2);
+        logger.add_log("retrying", 1);
+        logger.add_warning("retrying", 1);
+        logger.add_separator();
+        logger.add_log("done", 3);
+
+        use std_logger::LoggingType::*;
+        assert_eq!(
+            logger.summarize_by_message(),
+            vec![
+                (Warning, "retrying".to_string(), 2),
+                (Log, "retrying".to_string(), 2),
+                (Error, "timeout".to_string(), 1),
+                (Log, "done".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn context_around_errors_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        for i in 1..=10 {
+            if i == 3 || i == 5 || i == 10 {
+                logger.add_error("failed", i);
+            } else {
+                logger.add_log("step", i);
+            }
+        }
+
+        let indices: Vec<usize> = logger
+            .context_around_errors(1)
+            .iter()
+            .map(|entry| entry.index)
+            .collect();
+        assert_eq!(indices, vec![2, 3, 4, 5, 6, 9, 10]);
+        assert_eq!(logger.context_around_errors(0).len(), 3);
+        assert!(std_logger::Logger::new_logger()
+            .context_around_errors(5)
+            .is_empty());
+    }
+
+    #[test]
+    fn severity_override_test() {
+        let mut logger = std_logger::Logger::new_logger();
+        logger.set_warn_unparsed_errors(false);
+        logger.add_log("before", 500);
+        logger.set_severity_override(500, std_logger::LoggingType::Error);
+        logger.set_severity_override(0, std_logger::LoggingType::Warning);
+        logger.add_log("after", 500);
+        logger.add_marker(Some("marker"), Some(500));
+        logger.add_warning("unmapped", 501);
+        logger.add_separator();
+
+        let types: Vec<std_logger::LoggingType> =
+            logger.entries().map(|entry| entry.log_type).collect();
+        use std_logger::LoggingType::*;
+        assert_eq!(types, vec![Marker, Log, Error, Error, Warning, Marker]);
     }
 
     #[test]